@@ -0,0 +1,68 @@
+//! Benchmarks the parsing and both parts of every registered day of every year with Criterion.
+//!
+//! Each day is run on its real input if it has one, and on its example otherwise.
+//! Criterion warms up each step, collects statistics over many samples and compares them to the previous saved run,
+//! which is kept in `target/criterion`.
+//!
+//! Run with `cargo bench`, or `cargo bench -- 2022/day06` to only run some days.
+
+use aoc_2022::{
+    answer::Answer,
+    days,
+    error::SolveError,
+    inputs::InputDir,
+    solution::{Step, StepRunner},
+};
+use criterion::{
+    criterion_group, criterion_main, measurement::WallTime, BenchmarkGroup, Criterion,
+};
+
+/// A [`StepRunner`] that benchmarks each step in a Criterion benchmark group.
+struct CriterionRunner<'a, 'b> {
+    group: &'a mut BenchmarkGroup<'b, WallTime>,
+}
+
+impl StepRunner for CriterionRunner<'_, '_> {
+    fn run_step(&mut self, step: Step, step_fn: &mut dyn FnMut() -> Result<Answer, SolveError>) {
+        // Steps after a parse error aren't run, so the error only has to be reported once
+        if let Err(err) = step_fn() {
+            eprintln!("Skipping the rest of this day, since the input couldn't be parsed: {err}");
+            return;
+        }
+
+        let name = match step {
+            Step::Parse => "parse",
+            Step::PartOne => "part_one",
+            Step::PartTwo => "part_two",
+        };
+        self.group.bench_function(name, |b| b.iter(&mut *step_fn));
+    }
+}
+
+fn bench_days(c: &mut Criterion) {
+    for year in days::YEARS {
+        let input_dir = InputDir::from_env().year(year.year);
+        for solution in year.solutions {
+            let input = input_dir.read_input(solution.day).or_else(|_| {
+                InputDir::source()
+                    .year(year.year)
+                    .read_examples(solution.day)
+                    .map(|mut examples| examples.remove(0).text)
+            });
+            let Ok(input) = input else {
+                eprintln!(
+                    "Skipping {} day {}, which has no input or example",
+                    year.year, solution.day
+                );
+                continue;
+            };
+
+            let mut group = c.benchmark_group(format!("{}/day{:02}", year.year, solution.day));
+            (solution.run)(&input, &mut CriterionRunner { group: &mut group });
+            group.finish();
+        }
+    }
+}
+
+criterion_group!(benches, bench_days);
+criterion_main!(benches);
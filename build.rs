@@ -0,0 +1,17 @@
+use std::process::Command;
+
+/// Records the version of the compiler that builds the crate, since the `rustc` on the `PATH` at run time may be a
+/// different one.
+fn main() {
+    let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    let version = Command::new(rustc)
+        .arg("--version")
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok());
+    if let Some(version) = version {
+        println!("cargo:rustc-env=AOC_RUSTC_VERSION={}", version.trim());
+    }
+    println!("cargo:rerun-if-env-changed=RUSTC");
+}
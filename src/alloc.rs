@@ -0,0 +1,115 @@
+//! Allocation counting for the solutions, enabled with the `count-allocs` feature.
+//!
+//! The counters are kept per thread, so days running in parallel don't affect each other's counts.
+
+use std::cell::Cell;
+
+/// Allocations made while running a step of a solution.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    /// How many times memory was allocated or reallocated.
+    pub allocations: u64,
+    /// The total number of bytes allocated.
+    pub bytes: u64,
+    /// The largest number of bytes that were allocated at the same time.
+    pub peak_bytes: u64,
+}
+
+thread_local! {
+    static ALLOCATIONS: Cell<u64> = const { Cell::new(0) };
+    static ALLOCATED_BYTES: Cell<u64> = const { Cell::new(0) };
+    static LIVE_BYTES: Cell<i64> = const { Cell::new(0) };
+    static PEAK_LIVE_BYTES: Cell<i64> = const { Cell::new(0) };
+}
+
+/// Counts an allocation of `size` bytes and a deallocation of `freed` bytes on the current thread.
+#[cfg(feature = "count-allocs")]
+fn count(size: usize, freed: usize) {
+    // The thread locals might already be destroyed if this is called while a thread is exiting
+    let _ = ALLOCATIONS.try_with(|allocations| allocations.set(allocations.get() + 1));
+    let _ = ALLOCATED_BYTES.try_with(|bytes| bytes.set(bytes.get() + size as u64));
+    let _ = LIVE_BYTES.try_with(|live| {
+        live.set(live.get() + size as i64 - freed as i64);
+        let _ = PEAK_LIVE_BYTES.try_with(|peak| peak.set(peak.get().max(live.get())));
+    });
+}
+
+#[cfg(feature = "count-allocs")]
+fn count_free(size: usize) {
+    let _ = LIVE_BYTES.try_with(|live| live.set(live.get() - size as i64));
+}
+
+/// A global allocator that counts the allocations made on each thread before passing them on to the system allocator.
+#[cfg(feature = "count-allocs")]
+pub struct CountingAllocator;
+
+#[cfg(feature = "count-allocs")]
+unsafe impl std::alloc::GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: std::alloc::Layout) -> *mut u8 {
+        count(layout.size(), 0);
+        std::alloc::System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: std::alloc::Layout) -> *mut u8 {
+        count(layout.size(), 0);
+        std::alloc::System.alloc_zeroed(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: std::alloc::Layout) {
+        count_free(layout.size());
+        std::alloc::System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: std::alloc::Layout, new_size: usize) -> *mut u8 {
+        count(new_size, layout.size());
+        std::alloc::System.realloc(ptr, layout, new_size)
+    }
+}
+
+/// Runs a function and counts the allocations it makes on the current thread.
+///
+/// Returns [`None`] for the allocations if the `count-allocs` feature isn't enabled.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    if !cfg!(feature = "count-allocs") {
+        return (f(), None);
+    }
+
+    let allocations = ALLOCATIONS.get();
+    let bytes = ALLOCATED_BYTES.get();
+    let live = LIVE_BYTES.get();
+    // Only count the peak from the memory allocated by the function
+    PEAK_LIVE_BYTES.set(live);
+
+    let res = f();
+
+    let stats = AllocStats {
+        allocations: ALLOCATIONS.get() - allocations,
+        bytes: ALLOCATED_BYTES.get() - bytes,
+        peak_bytes: (PEAK_LIVE_BYTES.get() - live).max(0) as u64,
+    };
+    (res, Some(stats))
+}
+
+#[cfg(all(test, feature = "count-allocs"))]
+mod tests {
+    use super::*;
+
+    // The binary installs the allocator, so the library's tests need their own to have anything to count
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    #[test]
+    fn test_measure() {
+        let (_, stats) = measure(|| {
+            let first = vec![0u8; 1000];
+            drop(first);
+            let second = vec![0u8; 500];
+            second.len()
+        });
+        let stats = stats.unwrap();
+
+        assert_eq!(stats.allocations, 2);
+        assert_eq!(stats.bytes, 1500);
+        assert_eq!(stats.peak_bytes, 1000);
+    }
+}
@@ -0,0 +1,150 @@
+use std::fmt::Display;
+
+/// The answer to one part of a puzzle.
+///
+/// Solutions return their own answer types, which are converted into this so that the runner can render and compare
+/// answers without caring what type they were.
+///
+/// An integer and a text answer are equal if the text is how the integer is rendered, since digits can be returned
+/// either way, e.g. a code of digits returned as a string.
+#[derive(Clone, Debug, Default)]
+pub enum Answer {
+    /// A number, which most answers are.
+    Integer(i128),
+    /// A single line of text, e.g. the crates on top of each stack.
+    Text(String),
+    /// Several lines of text, e.g. letters drawn on a screen.
+    Block(String),
+    /// The part hasn't been solved yet.
+    #[default]
+    Unsolved,
+}
+
+impl Answer {
+    /// Parses an answer from text. Numbers become [`Answer::Integer`] and text with several lines becomes
+    /// [`Answer::Block`]. Digits that don't render back to the same text, like `007`, stay text.
+    pub fn parse(text: &str) -> Self {
+        match text.trim().parse::<i128>() {
+            Ok(number) if number.to_string() == text.trim() => Self::Integer(number),
+            _ => Self::from(text),
+        }
+    }
+
+    pub fn is_solved(&self) -> bool {
+        *self != Self::Unsolved
+    }
+
+    /// The lines of the answer. An integer or text answer has one line and an unsolved answer has none.
+    pub fn lines(&self) -> Vec<String> {
+        match self {
+            Self::Block(block) => block.lines().map(str::to_string).collect(),
+            Self::Unsolved => vec![],
+            answer => vec![answer.to_string()],
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Integer(a), Self::Integer(b)) => a == b,
+            (Self::Integer(number), Self::Text(text))
+            | (Self::Text(text), Self::Integer(number)) => number.to_string() == *text,
+            (Self::Text(a), Self::Text(b)) | (Self::Block(a), Self::Block(b)) => a == b,
+            (Self::Unsolved, Self::Unsolved) => true,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Answer {}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Integer(number) => write!(f, "{number}"),
+            Self::Text(text) | Self::Block(text) => write!(f, "{text}"),
+            Self::Unsolved => write!(f, "-"),
+        }
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for Answer {
+                fn from(number: $ty) -> Self {
+                    Self::Integer(number as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+impl From<u128> for Answer {
+    fn from(number: u128) -> Self {
+        i128::try_from(number).map_or_else(|_| Self::Text(number.to_string()), Self::Integer)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        // A block drawn line by line usually ends with a newline, which isn't part of the answer
+        let trimmed = text.trim_end_matches('\n');
+        if trimmed.contains('\n') {
+            Self::Block(trimmed.to_string())
+        } else if trimmed.len() == text.len() {
+            Self::Text(text)
+        } else {
+            Self::Text(trimmed.to_string())
+        }
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Self::from(text.to_string())
+    }
+}
+
+impl From<char> for Answer {
+    fn from(c: char) -> Self {
+        Self::Text(c.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from() {
+        assert_eq!(Answer::from(24000_u32), Answer::Integer(24000));
+        assert_eq!(Answer::from("CMZ"), Answer::Text("CMZ".to_string()));
+        assert_eq!(
+            Answer::from("#..#\n####\n"),
+            Answer::Block("#..#\n####".to_string())
+        );
+        assert_eq!(Answer::from(u128::MAX), Answer::Text(u128::MAX.to_string()));
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Answer::parse("24000"), Answer::Integer(24000));
+        assert_eq!(Answer::parse("-7"), Answer::Integer(-7));
+        assert_eq!(Answer::parse("MCD"), Answer::Text("MCD".to_string()));
+        assert!(matches!(Answer::parse("007"), Answer::Text(_)));
+    }
+
+    #[test]
+    fn test_eq() {
+        // A code of digits returned as a string matches an answer recorded as a number
+        assert_eq!(Answer::from("67384529"), Answer::Integer(67384529));
+        assert_eq!(Answer::Integer(45000), Answer::from("45000"));
+        assert_ne!(Answer::from("007"), Answer::Integer(7));
+        assert_ne!(Answer::Block("1".to_string()), Answer::Integer(1));
+        assert_ne!(Answer::Unsolved, Answer::from("-"));
+    }
+}
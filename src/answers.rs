@@ -0,0 +1,136 @@
+use std::{fs, io, path::Path};
+
+use toml::{Table, Value};
+
+use crate::answer::Answer;
+
+/// Expected answers for the puzzles, used to verify the solutions.
+///
+/// The answers are read from a TOML file with a table for each year and day:
+///
+/// ```toml
+/// [2022.day01]
+/// part_one = 24000
+/// part_two = 45000
+///
+/// [2022.day05]
+/// part_one = "CMZ"
+///
+/// # Answers for the input in `src/days/year2022/day05/inputs/alice.txt`
+/// [2022.day05.alice]
+/// part_one = "QNHWJVJZW"
+/// ```
+///
+/// Files from before years were added have top-level `[dayNN]` tables, which are read as the answers for 2022.
+#[derive(Debug, Default)]
+pub struct Answers(Table);
+
+impl Answers {
+    /// Loads the answers from a given file.
+    ///
+    /// If the file doesn't exist, there are no answers to verify against and this returns empty answers.
+    pub fn load(path: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        match fs::read_to_string(path) {
+            Ok(content) => Ok(Self(content.parse::<Table>()?)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Gets the expected answer for a given year, day, input and part, if one has been recorded.
+    /// The input is the name of a file in the day's `inputs` directory, or [`None`] for its `input.txt`.
+    ///
+    /// Strings that are numbers are read as integers, since large answers may not fit in a TOML integer.
+    pub fn get(&self, year: u16, day: u8, input: Option<&str>, part: u8) -> Option<Answer> {
+        let key = match part {
+            1 => "part_one",
+            2 => "part_two",
+            _ => return None,
+        };
+        let day_key = format!("day{day:02}");
+        let year_answers = self
+            .0
+            .get(&year.to_string())
+            .and_then(|table| table.get(&day_key));
+        let mut answers = match year_answers {
+            Some(answers) => answers,
+            // Files from before years were added only had answers for 2022
+            None if year == 2022 => self.0.get(&day_key)?,
+            None => return None,
+        };
+        if let Some(input) = input {
+            answers = answers.get(input)?;
+        }
+        match answers.get(key)? {
+            Value::String(answer) => Some(Answer::parse(answer)),
+            Value::Integer(answer) => Some(Answer::from(*answer)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get() {
+        let answers = Answers(
+            r#"
+            [2022.day01]
+            part_one = 24000
+
+            [2022.day05]
+            part_one = "CMZ"
+            part_two = "MCD"
+
+            [2022.day05.alice]
+            part_one = "QNHWJVJZW"
+
+            [2023.day01]
+            part_one = 142
+            "#
+            .parse()
+            .unwrap(),
+        );
+
+        assert_eq!(answers.get(2022, 1, None, 1), Some(Answer::Integer(24000)));
+        assert_eq!(answers.get(2022, 1, None, 2), None);
+        assert_eq!(answers.get(2022, 5, None, 2), Some(Answer::from("MCD")));
+        assert_eq!(
+            answers.get(2022, 5, Some("alice"), 1),
+            Some(Answer::from("QNHWJVJZW"))
+        );
+        assert_eq!(answers.get(2022, 5, Some("alice"), 2), None);
+        assert_eq!(answers.get(2022, 5, Some("bob"), 1), None);
+        assert_eq!(answers.get(2022, 6, None, 1), None);
+        assert_eq!(answers.get(2023, 1, None, 1), Some(Answer::Integer(142)));
+        assert_eq!(answers.get(2023, 5, None, 1), None);
+    }
+
+    #[test]
+    fn test_get_legacy() {
+        let answers = Answers(
+            r#"
+            [day01]
+            part_one = 24000
+
+            [day05.alice]
+            part_one = "QNHWJVJZW"
+
+            [2022.day06]
+            part_one = 7
+            "#
+            .parse()
+            .unwrap(),
+        );
+
+        assert_eq!(answers.get(2022, 1, None, 1), Some(Answer::Integer(24000)));
+        assert_eq!(
+            answers.get(2022, 5, Some("alice"), 1),
+            Some(Answer::from("QNHWJVJZW"))
+        );
+        assert_eq!(answers.get(2022, 6, None, 1), Some(Answer::Integer(7)));
+        assert_eq!(answers.get(2023, 1, None, 1), None);
+    }
+}
@@ -0,0 +1,108 @@
+use std::time::{Duration, Instant};
+
+use crate::answer::Answer;
+
+/// Statistics collected from running a puzzle solver repeatedly.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BenchStats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    /// The sample standard deviation, which divides by one less than the number of runs since the mean is estimated
+    /// from the same runs.
+    pub std_dev: Duration,
+    /// How many runs fall outside of the Tukey fences (1.5 × IQR below Q1 or above Q3).
+    pub outliers: usize,
+}
+
+impl BenchStats {
+    /// Computes the statistics for a given set of run durations.
+    ///
+    /// Returns [`None`] if there are no durations.
+    pub fn from_durations(durations: &[Duration]) -> Option<Self> {
+        if durations.is_empty() {
+            return None;
+        }
+
+        let mut sorted = durations.to_vec();
+        sorted.sort();
+
+        let nanos = sorted
+            .iter()
+            .map(|dur| dur.as_nanos() as f64)
+            .collect::<Vec<f64>>();
+        let mean = nanos.iter().sum::<f64>() / nanos.len() as f64;
+        let variance =
+            nanos.iter().map(|n| (n - mean).powi(2)).sum::<f64>() / (nanos.len() - 1).max(1) as f64;
+
+        let q1 = quantile(&nanos, 0.25);
+        let q3 = quantile(&nanos, 0.75);
+        let iqr = q3 - q1;
+        let (low_fence, high_fence) = (q1 - 1.5 * iqr, q3 + 1.5 * iqr);
+        let outliers = nanos
+            .iter()
+            .filter(|&&n| n < low_fence || n > high_fence)
+            .count();
+
+        Some(Self {
+            runs: sorted.len(),
+            min: sorted[0],
+            median: from_nanos(quantile(&nanos, 0.5)),
+            mean: from_nanos(mean),
+            std_dev: from_nanos(variance.sqrt()),
+            outliers,
+        })
+    }
+}
+
+/// Runs a step of a solution a given number of times and returns its last solution and the statistics of the runs.
+pub fn bench_step(
+    step_fn: &mut dyn FnMut() -> Answer,
+    runs: usize,
+) -> (Answer, Option<BenchStats>) {
+    let mut solution = Answer::Unsolved;
+    let mut durations = Vec::with_capacity(runs);
+
+    for _ in 0..runs {
+        let time = Instant::now();
+        solution = step_fn();
+        durations.push(time.elapsed());
+    }
+
+    (solution, BenchStats::from_durations(&durations))
+}
+
+fn from_nanos(nanos: f64) -> Duration {
+    Duration::from_nanos(nanos.round() as u64)
+}
+
+/// Gets a linearly interpolated quantile of sorted values.
+fn quantile(sorted: &[f64], q: f64) -> f64 {
+    let pos = (sorted.len() - 1) as f64 * q;
+    let (lower, upper) = (pos.floor() as usize, pos.ceil() as usize);
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (pos - lower as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let durations = [10, 12, 11, 13, 100].map(Duration::from_micros).to_vec();
+        let stats = BenchStats::from_durations(&durations).unwrap();
+
+        assert_eq!(stats.runs, 5);
+        assert_eq!(stats.min, Duration::from_micros(10));
+        assert_eq!(stats.median, Duration::from_micros(12));
+        assert_eq!(stats.mean, Duration::from_nanos(29_200));
+        assert_eq!(stats.std_dev, Duration::from_nanos(39_594));
+        assert_eq!(stats.outliers, 1);
+    }
+
+    #[test]
+    fn test_no_durations() {
+        assert_eq!(BenchStats::from_durations(&[]), None);
+    }
+}
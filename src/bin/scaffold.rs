@@ -1,3 +1,4 @@
+use aoc_2022::{days, inputs::InputDir};
 use clap::Parser;
 use regex::Regex;
 use reqwest::header::COOKIE;
@@ -6,35 +7,63 @@ use std::io::{BufRead, BufReader, Write};
 use std::ops::Range;
 use std::path::Path;
 
-const DAY_TEMPLATE: &str = r##"pub fn part_one(input: &str) -> Option<u32> {
-    None
-}
+const DAY_TEMPLATE: &str = r##"use crate::{error::SolveError, solution::Solution};
 
-pub fn part_two(input: &str) -> Option<u32> {
-    None
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = {day};
+    const TITLE: &'static str = {title};
+
+    type Input<'a> = &'a str;
+    type AnswerOne = u32;
+    type AnswerTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, SolveError> {
+        Ok(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Option<Self::AnswerOne> {
+        None
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Option<Self::AnswerTwo> {
+        None
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const EXAMPLE_INPUT: &str = r#""#;
+    const EXAMPLE_INPUT: &str = include_str!("example.txt");
 
     #[test]
     fn test_part_one() {
-        assert_eq!(part_one(EXAMPLE_INPUT), None);
+        assert_eq!(Puzzle::solve_part_one(EXAMPLE_INPUT), None);
     }
 
     #[test]
     fn test_part_two() {
-        assert_eq!(part_two(EXAMPLE_INPUT), None);
+        assert_eq!(Puzzle::solve_part_two(EXAMPLE_INPUT), None);
     }
 }
 "##;
 
+/// The module of a year that isn't registered yet, which starts out with the day being scaffolded.
+const YEAR_TEMPLATE: &str = r##"days! {
+    {year}:
+    day{day},
+}
+"##;
+
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 struct Args {
+    /// Year of the day to scaffold. Defaults to the latest year
+    #[arg(short, long, value_parser = clap::value_parser!(u16).range(2015..))]
+    year: Option<u16>,
+
     /// Day to scaffold
     #[arg(short, long)]
     day: u8,
@@ -65,26 +94,53 @@ fn create_file(file_path: &str, content: &str) -> std::io::Result<()> {
 }
 
 /// Gets the puzzle input using the user's session cookie.
-fn get_puzzle_input(day: u8) -> Result<String, Box<dyn std::error::Error>> {
+fn get_puzzle_input(year: u16, day: u8) -> Result<String, Box<dyn std::error::Error>> {
     let session_cookie =
         std::env::var("AOC_SESSION").expect("The `AOC_SESSION` environment variable should be set");
     let client = reqwest::blocking::Client::new();
     let res = client
-        .get(format!("https://adventofcode.com/2022/day/{day}/input"))
+        .get(format!("https://adventofcode.com/{year}/day/{day}/input"))
         .header(COOKIE, format!("session={session_cookie}"))
         .send()?;
     Ok(res.text()?.trim_end().to_string())
 }
 
+/// Gets the puzzle's title from its page, e.g. "Calorie Counting" for day 1.
+fn get_puzzle_title(year: u16, day: u8) -> Result<String, Box<dyn std::error::Error>> {
+    let client = reqwest::blocking::Client::new();
+    let res = client
+        .get(format!("https://adventofcode.com/{year}/day/{day}"))
+        .send()?;
+
+    let page_html = res.text()?;
+
+    // The title is in a heading like "--- Day 1: Calorie Counting ---"
+    let re = Regex::new(r"<h2>--- Day \d+: (.*?) ---</h2>")?;
+    let title = re
+        .captures(&page_html)
+        .and_then(|captures| captures.get(1))
+        .map_or("", |title| title.as_str());
+    Ok(html_escape_decode(title))
+}
+
+/// Decodes the few HTML entities that can appear in puzzle titles.
+fn html_escape_decode(text: &str) -> String {
+    text.replace("&amp;", "&")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+}
+
 /// Tries to get the puzzle description and parse it to markdown.
 ///
 /// If part 1 of the puzzle isn't completed or the session cookie can't be found, the 2nd part's description won't be available.
-fn _get_puzzle_description(day: u8) -> Result<String, Box<dyn std::error::Error>> {
+fn _get_puzzle_description(year: u16, day: u8) -> Result<String, Box<dyn std::error::Error>> {
     let session_cookie =
         std::env::var("AOC_SESSION").expect("The `AOC_SESSION` environment variable should be set");
     let client = reqwest::blocking::Client::new();
     let res = client
-        .get(format!("https://adventofcode.com/2022/day/{day}"))
+        .get(format!("https://adventofcode.com/{year}/day/{day}"))
         .header(COOKIE, format!("session={session_cookie}"))
         .send()?;
 
@@ -101,10 +157,10 @@ fn _get_puzzle_description(day: u8) -> Result<String, Box<dyn std::error::Error>
     Ok(markdown)
 }
 
-/// Finds the index of a line where code for a given day should be inserted below it.
+/// Finds the index of a line where code for a given day or year should be inserted below it.
 ///
 /// The appropriate line is found by matching each line in a given file to a given [`Regex`] pattern.
-/// To get the line's corresponding day number, this functions requires a range for where the day is in that pattern.
+/// To get the line's corresponding number, this functions requires a range for where the number is in that pattern.
 ///
 /// This function returns [`None`] if the index isn't found or if the line already exists.
 ///
@@ -123,7 +179,7 @@ fn _get_puzzle_description(day: u8) -> Result<String, Box<dyn std::error::Error>
 /// Now we want to add a match clause for the 2nd day below line 2 (index 1). We can find the index of that line like this:
 ///
 /// ```
-/// let line_idx = index_of_missing_day_line(
+/// let line_idx = index_of_missing_line(
 ///     2,
 ///     std::path::Path::new("/path/to/file.rs"),
 ///     regex::Regex::new(r"[\d_]+ => ()").unwrap(),
@@ -132,16 +188,16 @@ fn _get_puzzle_description(day: u8) -> Result<String, Box<dyn std::error::Error>
 ///
 /// assert_eq!(line_idx, Some(1));
 /// ```
-fn index_of_missing_day_line(
-    day: u8,
+fn index_of_missing_line(
+    number: u16,
     path: &Path,
     pattern: Regex,
-    pattern_day_range: Range<usize>,
+    pattern_number_range: Range<usize>,
 ) -> Option<usize> {
     let file = fs::File::options().read(true).open(path).unwrap();
     let reader = BufReader::new(file);
 
-    let mut prev_line_day = None;
+    let mut prev_line_number = None;
     let mut line_idx = 0;
 
     for line in reader.lines() {
@@ -152,35 +208,35 @@ fn index_of_missing_day_line(
             .collect::<Vec<&str>>();
 
         if let Some(match_case) = matches.first() {
-            // Try to parse the given range in the pattern into a day number
-            if let Ok(line_day) = match_case[pattern_day_range.clone()]
+            // Try to parse the given range in the pattern into a number
+            if let Ok(line_number) = match_case[pattern_number_range.clone()]
                 .to_string()
                 .trim()
-                .parse::<u8>()
+                .parse::<u16>()
             {
-                if prev_line_day.is_none() {
-                    prev_line_day = Some(line_day);
+                if prev_line_number.is_none() {
+                    prev_line_number = Some(line_number);
                 }
 
                 // If the line already exists, do nothing
-                if line_day == day {
+                if line_number == number {
                     return None;
                 }
 
-                // If the day falls between these two lines, the index has been found
-                if prev_line_day.unwrap() < day && (line_day > day) {
+                // If the number falls between these two lines, the index has been found
+                if prev_line_number.unwrap() < number && (line_number > number) {
                     return Some(line_idx);
                 }
             }
-        } else if prev_line_day.is_some() {
-            // If the line can't be parsed but the previous day is defined, the index is the previous line's index
-            return Some(line_idx - 1);
+        } else if prev_line_number.is_some() {
+            // If the line doesn't match but the previous number is defined, the index is this line's index
+            return Some(line_idx);
         }
         line_idx += 1;
     }
 
-    // If EOF reached but the previous day is defined, the index is at the end
-    if prev_line_day.is_some() {
+    // If EOF reached but the previous number is defined, the index is at the end
+    if prev_line_number.is_some() {
         return Some(line_idx);
     }
     None
@@ -188,68 +244,72 @@ fn index_of_missing_day_line(
 
 fn main() {
     let args = Args::parse();
+    let year = args.year.unwrap_or_else(days::latest_year);
 
-    // Find absolute path to project directory
-    let exe_path = std::env::current_exe().unwrap();
-    let project_dir_idx = exe_path
-        .iter()
-        .position(|str| str.to_str().unwrap() == "advent-of-code-2022")
-        .unwrap();
-    let project_path =
-        exe_path.to_str().unwrap().split('/').collect::<Vec<&str>>()[0..=project_dir_idx].join("/");
+    // The project directory is known at compile time, so this works from any working directory
+    let project_path = env!("CARGO_MANIFEST_DIR");
 
     // Create the day's files
 
-    let day_dir_path_str = format!("{}/src/days/day{:02}", project_path, args.day);
+    let year_dir_path_str = format!("{}/src/days/year{}", project_path, year);
+    let day_dir_path_str = format!("{}/day{:02}", year_dir_path_str, args.day);
 
-    create_file(&format!("{}/mod.rs", day_dir_path_str), DAY_TEMPLATE).unwrap();
+    let title = get_puzzle_title(year, args.day).unwrap_or_default();
+    let day_code = DAY_TEMPLATE
+        .replace("{day}", &args.day.to_string())
+        // Debug formatting quotes and escapes the title, which can have quotes or backslashes in it
+        .replace("{title}", &format!("{title:?}"));
+    create_file(&format!("{}/mod.rs", day_dir_path_str), &day_code).unwrap();
 
+    // The input goes wherever the runner reads inputs from
     create_file(
-        &format!("{}/input.txt", day_dir_path_str),
-        &get_puzzle_input(args.day).expect("Couldn't get puzzle input"),
+        InputDir::from_env()
+            .year(year)
+            .input_path(args.day)
+            .to_str()
+            .unwrap(),
+        &get_puzzle_input(year, args.day).expect("Couldn't get puzzle input"),
     )
     .unwrap();
 
+    // The example has to be pasted in by hand
+    create_file(&format!("{}/example.txt", day_dir_path_str), "").unwrap();
+
     // Uncomment to enable generation of puzzle descriptions
     /*
-    if let Ok(desc) = _get_puzzle_description(args.day) {
+    if let Ok(desc) = _get_puzzle_description(year, args.day) {
         create_file(&format!("{}/README.md", day_dir_path_str), &desc).unwrap();
     }
     */
 
-    let main_path_str = format!("{}/src/main.rs", project_path);
-    let main_path = std::path::Path::new(&main_path_str);
-    let target_line_idx = index_of_missing_day_line(
-        args.day,
-        main_path,
-        Regex::new(r"[\d_]+ => ").unwrap(),
-        0..2,
+    // A new year's module is created with the day already registered
+    let year_mod_path_str = format!("{}/mod.rs", year_dir_path_str);
+    let year_code = YEAR_TEMPLATE
+        .replace("{year}", &year.to_string())
+        .replace("{day}", &format!("{:02}", args.day));
+    create_file(&year_mod_path_str, &year_code).unwrap();
+
+    let year_mod_path = std::path::Path::new(&year_mod_path_str);
+    let target_line_idx = index_of_missing_line(
+        args.day.into(),
+        year_mod_path,
+        Regex::new(r"day\d\d,").unwrap(),
+        3..5,
     );
-    // If the correct line index has been found, insert a line for the new match case below that line
+
+    // If the correct line index has been found, register the new day's module on that line
     if let Some(i) = target_line_idx {
-        insert_below_line(
-            &format!(
-                "        {} => DayResult::from_solvers(input, day{:02}::part_one, day{:02}::part_two),",
-                args.day, args.day, args.day
-            ),
-            i,
-            main_path,
-        )
-        .unwrap();
+        insert_below_line(&format!("    day{:02},", args.day), i, year_mod_path).unwrap();
     }
 
     let mod_path_str = format!("{}/src/days/mod.rs", project_path);
     let mod_path = std::path::Path::new(&mod_path_str);
-    let target_line_idx = index_of_missing_day_line(
-        args.day,
-        mod_path,
-        Regex::new(r"pub mod day\d\d;").unwrap(),
-        11..13,
-    );
+    let target_line_idx =
+        index_of_missing_line(year, mod_path, Regex::new(r"year\d{4},").unwrap(), 4..8);
 
-    // If the correct line index has been found, insert a line for the new match case below that line
+    // Register the year's module too if it's new
     if let Some(i) = target_line_idx {
-        insert_below_line(&format!("pub mod day{:02};", args.day), i, mod_path).unwrap();
+        insert_below_line(&format!("    year{year},"), i, mod_path).unwrap();
     }
 }
 
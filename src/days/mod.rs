@@ -1,8 +1,91 @@
-pub mod day01;
-pub mod day02;
-pub mod day03;
-pub mod day04;
-pub mod day05;
-pub mod day06;
-pub mod day07;
-pub mod day08;
\ No newline at end of file
+use crate::solution::RegisteredSolution;
+
+/// Declares the day modules of a year and registers each module's `Puzzle` solution.
+///
+/// Each puzzle's `DAY` has to match its module's name, so that a copied module can't shadow another day. Since module
+/// names are unique, so are the days.
+macro_rules! days {
+    ($year:literal: $($day:ident),* $(,)?) => {
+        $(pub mod $day;)*
+
+        $(const _: () = assert!(
+            <$day::Puzzle as $crate::solution::Solution>::DAY as u16
+                == $crate::days::number_in_name(stringify!($day)),
+            concat!("The `DAY` of `", stringify!($day), "::Puzzle` doesn't match its module"),
+        );)*
+
+        /// The year of the puzzles.
+        pub const YEAR: u16 = $year;
+
+        /// All registered solutions of the year, ordered by day.
+        pub const SOLUTIONS: &[$crate::solution::RegisteredSolution] =
+            &[$($crate::solution::RegisteredSolution::of::<$day::Puzzle>(YEAR)),*];
+    };
+}
+
+/// Declares the year modules and registers the solutions of each year.
+macro_rules! years {
+    ($($year:ident),* $(,)?) => {
+        $(pub mod $year;)*
+
+        $(const _: () = assert!(
+            $year::YEAR == $crate::days::number_in_name(stringify!($year)),
+            concat!("The `YEAR` of `", stringify!($year), "` doesn't match its module"),
+        );)*
+
+        /// All years, ordered by year.
+        pub const YEARS: &[Year] = &[$(Year { year: $year::YEAR, solutions: $year::SOLUTIONS }),*];
+    };
+}
+
+/// Reads the number at the end of a module name like `day07` or `year2022`.
+#[doc(hidden)]
+pub const fn number_in_name(name: &str) -> u16 {
+    let bytes = name.as_bytes();
+    let mut number = 0;
+    let mut idx = 0;
+    while idx < bytes.len() {
+        if bytes[idx].is_ascii_digit() {
+            number = number * 10 + (bytes[idx] - b'0') as u16;
+        }
+        idx += 1;
+    }
+    number
+}
+
+/// A year of puzzles along with its registered solutions.
+pub struct Year {
+    pub year: u16,
+    pub solutions: &'static [RegisteredSolution],
+}
+
+years! {
+    year2022,
+}
+
+/// Finds a given year.
+pub fn get_year(year: u16) -> Option<&'static Year> {
+    YEARS.iter().find(|entry| entry.year == year)
+}
+
+/// The latest year, which is run when no year is given.
+pub fn latest_year() -> u16 {
+    YEARS.last().expect("There are no years").year
+}
+
+/// Finds the registered solution for a given year and day.
+pub fn get_solution(year: u16, day: u8) -> Option<&'static RegisteredSolution> {
+    let solutions = get_year(year)?.solutions;
+    solutions.iter().find(|solution| solution.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_number_in_name() {
+        assert_eq!(number_in_name("day07"), 7);
+        assert_eq!(number_in_name("year2022"), 2022);
+    }
+}
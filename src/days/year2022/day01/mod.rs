@@ -0,0 +1,57 @@
+use crate::{error::SolveError, solution::Solution, utils};
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Calorie Counting";
+
+    type Input<'a> = Vec<Vec<u32>>;
+    type AnswerOne = u32;
+    type AnswerTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, SolveError> {
+        parse_input(input)
+    }
+
+    fn part_one(calory_lists: &Self::Input<'_>) -> Option<Self::AnswerOne> {
+        let mut calory_totals: Vec<u32> = sub_sum(calory_lists);
+        calory_totals.sort_by(|a, b| b.cmp(a)); // Sort descending
+        Some(calory_totals[0])
+    }
+
+    fn part_two(calory_lists: &Self::Input<'_>) -> Option<Self::AnswerTwo> {
+        let mut calory_totals: Vec<u32> = sub_sum(calory_lists);
+        calory_totals.sort_by(|a, b| b.cmp(a)); // Sort descending
+        // There are no top three elves to add up if there are fewer than three
+        Some(calory_totals.get(0..3)?.iter().sum())
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Vec<u32>>, SolveError> {
+    let lists = input.split("\n\n");
+    lists.map(|l| utils::parse_strings(l.split('\n'))).collect()
+}
+
+/// Iterates over a slice of number vectors and returns a vector with the items of the sub vectors summed.
+pub fn sub_sum(items: &[Vec<u32>]) -> Vec<u32> {
+    items.iter().map(|vec| vec.iter().sum()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_INPUT: &str = include_str!("example.txt");
+
+    #[test]
+    fn test_part_one() {
+        assert_eq!(Puzzle::solve_part_one(EXAMPLE_INPUT), Some(24_000));
+    }
+
+    #[test]
+    fn test_part_two() {
+        assert_eq!(Puzzle::solve_part_two(EXAMPLE_INPUT), Some(45_000));
+        assert_eq!(Puzzle::solve_part_two("1000\n\n2000"), None);
+    }
+}
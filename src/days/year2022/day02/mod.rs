@@ -0,0 +1,176 @@
+use crate::{error::SolveError, solution::Solution, utils};
+
+/// An outcome with its score as the value
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Outcome {
+    Win = 6,
+    Draw = 3,
+    Loss = 0,
+}
+
+/// A shape with its score as the value
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Shape {
+    Rock = 1,
+    Paper = 2,
+    Scissors = 3,
+}
+
+impl Shape {
+    /// Gets the opponent's shape from its symbol.
+    fn from_symbol(symbol: &str) -> Option<Self> {
+        match symbol {
+            "A" => Some(Self::Rock),
+            "B" => Some(Self::Paper),
+            "C" => Some(Self::Scissors),
+            _ => None,
+        }
+    }
+}
+
+/// The second column of the strategy guide, which is read as a shape in part one and as an outcome in part two.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Response {
+    X,
+    Y,
+    Z,
+}
+
+impl Response {
+    fn from_symbol(symbol: &str) -> Option<Self> {
+        match symbol {
+            "X" => Some(Self::X),
+            "Y" => Some(Self::Y),
+            "Z" => Some(Self::Z),
+            _ => None,
+        }
+    }
+
+    /// Reads the response as the shape to play.
+    fn shape(self) -> Shape {
+        match self {
+            Self::X => Shape::Rock,
+            Self::Y => Shape::Paper,
+            Self::Z => Shape::Scissors,
+        }
+    }
+
+    /// Reads the response as the outcome the round should end with.
+    fn outcome(self) -> Outcome {
+        match self {
+            Self::X => Outcome::Loss,
+            Self::Y => Outcome::Draw,
+            Self::Z => Outcome::Win,
+        }
+    }
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Rock Paper Scissors";
+
+    type Input<'a> = Vec<(Shape, Response)>;
+    type AnswerOne = u32;
+    type AnswerTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, SolveError> {
+        parse_input(input)
+    }
+
+    fn part_one(rounds: &Self::Input<'_>) -> Option<Self::AnswerOne> {
+        let mut total_score = 0;
+
+        for &(opponent_shape, response) in rounds {
+            let player_shape = response.shape();
+            total_score += player_shape as u32 + get_outcome(player_shape, opponent_shape) as u32;
+        }
+
+        Some(total_score)
+    }
+
+    fn part_two(rounds: &Self::Input<'_>) -> Option<Self::AnswerTwo> {
+        let mut total_score = 0;
+
+        for &(opponent_shape, response) in rounds {
+            let player_shape = shape_for_desired_outcome(response.outcome(), opponent_shape);
+            total_score += player_shape as u32 + get_outcome(player_shape, opponent_shape) as u32;
+        }
+
+        Some(total_score)
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<(Shape, Response)>, SolveError> {
+    let rounds = input.split('\n');
+    rounds.map(parse_round).collect()
+}
+
+/// Parses a round like "A Y" into the opponent's shape and the player's response.
+fn parse_round(round: &str) -> Result<(Shape, Response), SolveError> {
+    let (opponent, player) = utils::split_once(round, " ")?;
+    let opponent = Shape::from_symbol(opponent)
+        .ok_or_else(|| SolveError::new("expected `A`, `B` or `C`", opponent))?;
+    let player = Response::from_symbol(player)
+        .ok_or_else(|| SolveError::new("expected `X`, `Y` or `Z`", player))?;
+    Ok((opponent, player))
+}
+
+/// Gets the outcome of playing a given shape against another given shape.
+fn get_outcome(shape: Shape, against_shape: Shape) -> Outcome {
+    match shape {
+        Shape::Rock => match against_shape {
+            Shape::Rock => Outcome::Draw,
+            Shape::Paper => Outcome::Loss,
+            Shape::Scissors => Outcome::Win,
+        },
+        Shape::Paper => match against_shape {
+            Shape::Rock => Outcome::Win,
+            Shape::Paper => Outcome::Draw,
+            Shape::Scissors => Outcome::Loss,
+        },
+        Shape::Scissors => match against_shape {
+            Shape::Rock => Outcome::Loss,
+            Shape::Paper => Outcome::Win,
+            Shape::Scissors => Outcome::Draw,
+        },
+    }
+}
+
+/// Finds the shape required to get a given outcome against a given shape.
+fn shape_for_desired_outcome(outcome: Outcome, against_shape: Shape) -> Shape {
+    match outcome {
+        // Play shape that wins
+        Outcome::Win => match against_shape {
+            Shape::Rock => Shape::Paper,
+            Shape::Paper => Shape::Scissors,
+            Shape::Scissors => Shape::Rock,
+        },
+        // Play shape that loses
+        Outcome::Loss => match against_shape {
+            Shape::Rock => Shape::Scissors,
+            Shape::Paper => Shape::Rock,
+            Shape::Scissors => Shape::Paper,
+        },
+        // Play the same shape
+        Outcome::Draw => against_shape,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_INPUT: &str = include_str!("example.txt");
+
+    #[test]
+    fn test_part_one() {
+        assert_eq!(Puzzle::solve_part_one(EXAMPLE_INPUT), Some(15));
+    }
+
+    #[test]
+    fn test_part_two() {
+        assert_eq!(Puzzle::solve_part_two(EXAMPLE_INPUT), Some(12));
+    }
+}
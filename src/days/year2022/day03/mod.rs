@@ -0,0 +1,82 @@
+use std::collections::BTreeSet;
+
+use crate::{error::SolveError, solution::Solution, utils};
+
+const ALPHABET: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Rucksack Reorganization";
+
+    type Input<'a> = Vec<&'a str>;
+    type AnswerOne = u32;
+    type AnswerTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, SolveError> {
+        input.lines().map(parse_sack).collect()
+    }
+
+    fn part_one(sacks: &Self::Input<'_>) -> Option<Self::AnswerOne> {
+        let sack_compartments = sacks
+            .iter()
+            .flat_map(|sack| {
+                let (first, second) = sack.split_at(sack.len() / 2);
+                vec![first.chars().collect(), second.chars().collect()]
+            })
+            .collect();
+
+        sum_of_common_items(sack_compartments, 2)
+    }
+
+    fn part_two(sacks: &Self::Input<'_>) -> Option<Self::AnswerTwo> {
+        let sacks = sacks.iter().map(|sack| sack.chars().collect()).collect();
+        sum_of_common_items(sacks, 3)
+    }
+}
+
+/// Checks that a rucksack only has items, i.e. letters, split evenly between its two compartments.
+fn parse_sack(sack: &str) -> Result<&str, SolveError> {
+    if let Some(idx) = sack.find(|c: char| !c.is_ascii_alphabetic()) {
+        let item = &sack[idx..idx + sack[idx..].chars().next().map_or(0, char::len_utf8)];
+        return Err(SolveError::new("expected a letter", item));
+    }
+    if !sack.len().is_multiple_of(2) {
+        return Err(SolveError::new("expected an even number of items", sack));
+    }
+    Ok(sack)
+}
+
+/// Finds the common items in rucksack groups of a given size and returns the sum of their priorities.
+///
+/// Returns [`None`] if a group has no item in common.
+fn sum_of_common_items(sacks: Vec<BTreeSet<char>>, chunk_size: usize) -> Option<u32> {
+    sacks.chunks(chunk_size).try_fold(0, |priority_acc, group| {
+        let intersection = utils::intersection(group.iter());
+        Some(priority_acc + item_to_priority(intersection.into_iter().next()?))
+    })
+}
+
+/// Gets the priority of a given item type.
+fn item_to_priority(character: char) -> u32 {
+    ALPHABET.find(character).unwrap() as u32 + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_INPUT: &str = include_str!("example.txt");
+
+    #[test]
+    fn test_part_one() {
+        assert_eq!(Puzzle::solve_part_one(EXAMPLE_INPUT), Some(157));
+        assert_eq!(Puzzle::solve_part_one("abcd"), None);
+    }
+
+    #[test]
+    fn test_part_two() {
+        assert_eq!(Puzzle::solve_part_two(EXAMPLE_INPUT), Some(70));
+    }
+}
@@ -0,0 +1,89 @@
+use std::ops::RangeInclusive;
+
+use crate::{error::SolveError, solution::Solution, utils};
+
+/// The section ranges assigned to a pair of elves.
+type Pair = (RangeInclusive<usize>, RangeInclusive<usize>);
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Camp Cleanup";
+
+    type Input<'a> = Vec<Pair>;
+    type AnswerOne = u32;
+    type AnswerTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, SolveError> {
+        parse_input(input)
+    }
+
+    fn part_one(pairs: &Self::Input<'_>) -> Option<Self::AnswerOne> {
+        Some(pairs.iter().fold(0, |acc, pair| acc + ranges_fully_overlap(pair) as u32))
+    }
+
+    fn part_two(pairs: &Self::Input<'_>) -> Option<Self::AnswerTwo> {
+        Some(pairs.iter().fold(0, |acc, pair| acc + ranges_overlap(pair) as u32))
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Pair>, SolveError> {
+    input.lines().map(parse_pair_string).collect()
+}
+
+/// Parses a string like "2-4,6-8" into a pair of inclusive ranges.
+fn parse_pair_string(pair: &str) -> Result<Pair, SolveError> {
+    let (first, second) = utils::split_once(pair, ",")?;
+    Ok((parse_range(first)?, parse_range(second)?))
+}
+
+/// Parses a string like "2-4" into an inclusive range.
+fn parse_range(range: &str) -> Result<RangeInclusive<usize>, SolveError> {
+    let (start, end) = utils::split_once(range, "-")?;
+    Ok(utils::parse_number(start)?..=utils::parse_number(end)?)
+}
+
+/// Checks if ranges fully overlap.
+fn ranges_fully_overlap((a, b): &Pair) -> bool {
+    a.contains(b.start()) && a.contains(b.end()) || b.contains(a.start()) && b.contains(a.end())
+}
+
+/// Checks if ranges overlap at all.
+fn ranges_overlap((a, b): &Pair) -> bool {
+    a.contains(b.start()) || b.contains(a.start()) || a.contains(b.end()) || b.contains(a.end())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_INPUT: &str = include_str!("example.txt");
+
+    #[test]
+    fn test_part_one() {
+        assert_eq!(Puzzle::solve_part_one(EXAMPLE_INPUT), Some(2));
+    }
+
+    #[test]
+    fn test_part_two() {
+        assert_eq!(Puzzle::solve_part_two(EXAMPLE_INPUT), Some(4));
+    }
+
+    #[test]
+    fn test_malformed_input() {
+        let input = "2-4,6-8\n2-3;4-5";
+        let err = Puzzle::parse(input).unwrap_err().locate(input);
+        assert_eq!(
+            err.to_string(),
+            "expected `,`: `2-3;4-5` at line 2, column 1"
+        );
+
+        let input = "2-4,6-8\n2-x,4-5";
+        let err = Puzzle::parse(input).unwrap_err().locate(input);
+        assert_eq!(
+            err.to_string(),
+            "expected a number: `x` at line 2, column 3"
+        );
+    }
+}
@@ -0,0 +1,182 @@
+use crate::{error::SolveError, solution::Solution, utils};
+
+type Crate = char;
+
+#[derive(Debug)]
+pub struct MoveStep {
+    /// How many crates to move from one stack to another.
+    quantity: usize,
+    /// Which crate stack to move crates from.\
+    /// **Note**: Start index is 1.
+    from: usize,
+    /// Which crate stack to move crates on top of.\
+    /// **Note**: Start index is 1.
+    to: usize,
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "Supply Stacks";
+
+    type Input<'a> = (Vec<Vec<Crate>>, Vec<MoveStep>);
+    type AnswerOne = String;
+    type AnswerTwo = String;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, SolveError> {
+        parse_input(input)
+    }
+
+    fn part_one((crates, steps): &Self::Input<'_>) -> Option<Self::AnswerOne> {
+        let mut crates = crates.clone();
+
+        for step in steps {
+            let lifted = lift_crates(&mut crates[step.from - 1], step.quantity);
+            // Crates are lifted one by one, so they are unloaded in reverse order.
+            crates[step.to - 1].extend(lifted.iter().rev());
+        }
+
+        Some(top_crates(&crates))
+    }
+
+    fn part_two((crates, steps): &Self::Input<'_>) -> Option<Self::AnswerTwo> {
+        let mut crates = crates.clone();
+
+        for step in steps {
+            let lifted = lift_crates(&mut crates[step.from - 1], step.quantity);
+            crates[step.to - 1].extend(lifted);
+        }
+
+        Some(top_crates(&crates))
+    }
+}
+
+/// Gets the crates on top of each stack. Stacks that end up empty have no crate to add.
+fn top_crates(crates: &[Vec<Crate>]) -> String {
+    crates.iter().filter_map(|stack| stack.last()).collect()
+}
+
+/// Lifts (removes) crates from a given crate stack and returns the lifted crates as a vector.
+fn lift_crates(crate_stack: &mut Vec<Crate>, quantity: usize) -> Vec<Crate> {
+    let lifted = crate_stack.drain(crate_stack.len() - quantity..crate_stack.len());
+    lifted.collect::<Vec<Crate>>()
+}
+
+fn parse_input(input: &str) -> Result<(Vec<Vec<Crate>>, Vec<MoveStep>), SolveError> {
+    let Some((crates, steps)) = input.split_once("\n\n") else {
+        return Err(SolveError::new(
+            "expected a blank line between the crates and the steps",
+            &input[input.len()..],
+        ));
+    };
+    let crates = parse_crates(crates);
+    let heights = crates.iter().map(Vec::len).collect();
+    let steps = parse_steps(steps, heights)?;
+    Ok((crates, steps))
+}
+
+/// Constructs a `Vec<Vec<Crate>>` from a string input. Each subvector is a crate stack with the last item being the crate on top.
+fn parse_crates(crates: &str) -> Vec<Vec<Crate>> {
+    // The rows of crates can be cut short when their trailing spaces are trimmed, but there's a label for every stack
+    let stack_count = crates.lines().last().map_or(0, |labels| labels.split_whitespace().count());
+    let mut stacks: Vec<Vec<Crate>> = vec![];
+
+    // Loop through the crate stacks. The crate labels start with an offset of 1 and are 4 characters apart.
+    for (stack_idx, x) in (0..stack_count).map(|idx| 1 + idx * 4).enumerate() {
+        stacks.push(vec![]);
+
+        // Loop through the rows of the crate stack from bottom to top.
+        for row in crates.lines().rev() {
+            // Rows without crates on the right can be cut short
+            let crate_name = row.chars().nth(x).unwrap_or(' ');
+
+            // Add crate to the stack if it's valid.
+            if crate_name.is_alphabetic() {
+                stacks[stack_idx].push(crate_name);
+            }
+        }
+    }
+
+    stacks
+}
+
+/// Parses rearrangement procedure steps from a string into a `Vec<MoveStep>`.
+/// The stacks that crates are moved between have to be one of the stacks with the given heights, and a step can't
+/// move more crates than its stack has at that point.
+fn parse_steps(steps: &str, mut heights: Vec<usize>) -> Result<Vec<MoveStep>, SolveError> {
+    let stack_count = heights.len();
+    steps
+        .lines()
+        .map(|line| {
+            // Step format: "move <num> from <num> to <num>"
+            let (quantity, rest) = utils::split_once(strip_word(line, "move ")?, " ")?;
+            let (from, rest) = utils::split_once(strip_word(rest, "from ")?, " ")?;
+            let to = strip_word(rest, "to ")?;
+
+            let parse_stack = |stack: &str| match utils::parse_number(stack)? {
+                idx @ 1.. if idx <= stack_count => Ok(idx),
+                _ => Err(SolveError::new(
+                    format!("expected a stack from 1 to {stack_count}"),
+                    stack,
+                )),
+            };
+            let step = MoveStep {
+                quantity: utils::parse_number(quantity)?,
+                from: parse_stack(from)?,
+                to: parse_stack(to)?,
+            };
+
+            let height = heights[step.from - 1];
+            if step.quantity > height {
+                let message = format!(
+                    "expected at most {height} crates, which is how many are on stack {}",
+                    step.from
+                );
+                return Err(SolveError::new(message, quantity));
+            }
+            heights[step.from - 1] -= step.quantity;
+            heights[step.to - 1] += step.quantity;
+            Ok(step)
+        })
+        .collect()
+}
+
+/// Strips a given word from the start of a step.
+fn strip_word<'a>(text: &'a str, word: &str) -> Result<&'a str, SolveError> {
+    text.strip_prefix(word)
+        .ok_or_else(|| SolveError::new(format!("expected `{}`", word.trim_end()), text))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_INPUT: &str = include_str!("example.txt");
+
+    #[test]
+    fn test_part_one() {
+        assert_eq!(Puzzle::solve_part_one(EXAMPLE_INPUT), Some("CMZ".to_string()));
+    }
+
+    #[test]
+    fn test_part_two() {
+        assert_eq!(Puzzle::solve_part_two(EXAMPLE_INPUT), Some("MCD".to_string()));
+    }
+
+    #[test]
+    fn test_trimmed_rows() {
+        let input = "    [D]\n[N] [C]\n[Z] [M] [P]\n 1   2   3\n\nmove 1 from 2 to 1\nmove 3 from 1 to 3";
+        assert_eq!(Puzzle::solve_part_one(input), Some("CZ".to_string()));
+    }
+
+    #[test]
+    fn test_parse_too_many_crates() {
+        let input = "[A]    \n[B] [C]\n 1   2 \n\nmove 1 from 1 to 2\nmove 3 from 2 to 1";
+        let err = Puzzle::parse(input).unwrap_err().locate(input);
+        assert_eq!(
+            err.to_string(),
+            "expected at most 2 crates, which is how many are on stack 2: `3` at line 6, column 6"
+        );
+    }
+}
@@ -0,0 +1,86 @@
+use crate::{error::SolveError, solution::Solution};
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Tuning Trouble";
+
+    type Input<'a> = Vec<char>;
+    type AnswerOne = u32;
+    type AnswerTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, SolveError> {
+        Ok(input.chars().collect())
+    }
+
+    fn part_one(chars: &Self::Input<'_>) -> Option<Self::AnswerOne> {
+        find_unique_sequence_end(chars, 4)
+    }
+
+    fn part_two(chars: &Self::Input<'_>) -> Option<Self::AnswerTwo> {
+        find_unique_sequence_end(chars, 14)
+    }
+}
+
+fn find_unique_sequence_end(chars: &[char], sequence_length: usize) -> Option<u32> {
+    let mut seq_windows = chars.windows(sequence_length).enumerate();
+
+    'outer: while let Some((sequence_start, sequence)) = seq_windows.next() {
+        // Iterate sequence in reverse. When a duplicate value is found, we can skip until the current index.
+        for (i, char_1) in sequence.iter().rev().enumerate() {
+            for (j, char_2) in sequence[0..sequence.len() - i - 1].iter().rev().enumerate() {
+                // Duplicate value found, skip to current index if the current index is larger than the minimum sequence length.
+                if char_1 == char_2 {
+                    let advance_by = sequence_length - j - i - 1;
+                    for _ in 0..advance_by - 1 {
+                        seq_windows.next();
+                    }
+                    continue 'outer;
+                }
+            }
+        }
+        return Some(sequence_start as u32 + sequence_length as u32);
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_1() {
+        let input = include_str!("example.txt");
+        assert_eq!(Puzzle::solve_part_one(input), Some(7));
+        assert_eq!(Puzzle::solve_part_two(input), Some(19));
+    }
+
+    #[test]
+    fn example_2() {
+        let input = include_str!("example2.txt");
+        assert_eq!(Puzzle::solve_part_one(input), Some(5));
+        assert_eq!(Puzzle::solve_part_two(input), Some(23));
+    }
+
+    #[test]
+    fn example_3() {
+        let input = include_str!("example3.txt");
+        assert_eq!(Puzzle::solve_part_one(input), Some(6));
+        assert_eq!(Puzzle::solve_part_two(input), Some(23));
+    }
+
+    #[test]
+    fn example_4() {
+        let input = include_str!("example4.txt");
+        assert_eq!(Puzzle::solve_part_one(input), Some(10));
+        assert_eq!(Puzzle::solve_part_two(input), Some(29));
+    }
+
+    #[test]
+    fn example_5() {
+        let input = include_str!("example5.txt");
+        assert_eq!(Puzzle::solve_part_one(input), Some(11));
+        assert_eq!(Puzzle::solve_part_two(input), Some(26));
+    }
+}
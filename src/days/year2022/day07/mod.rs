@@ -0,0 +1,147 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use crate::{error::SolveError, solution::Solution, utils};
+
+#[derive(Clone, Debug)]
+pub struct Directory {
+    sub_directories: HashMap<String, Rc<RefCell<Directory>>>,
+    parent: Option<Rc<RefCell<Directory>>>,
+    total_size: u32,
+}
+
+impl Directory {
+    /// Adds a given file size to the directory's total size and updates all parent directories.
+    fn add_file_size(&mut self, size: u32) {
+        self.total_size += size;
+
+        if let Some(parent) = &mut self.parent {
+            parent.borrow_mut().add_file_size(size);
+        }
+    }
+    fn empty_with_parent(parent: Option<Rc<RefCell<Directory>>>) -> Self {
+        Directory {
+            sub_directories: HashMap::new(),
+            parent,
+            total_size: 0,
+        }
+    }
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "No Space Left On Device";
+
+    type Input<'a> = Rc<RefCell<Directory>>;
+    type AnswerOne = u32;
+    type AnswerTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, SolveError> {
+        construct_file_system(input)
+    }
+
+    fn part_one(file_system: &Self::Input<'_>) -> Option<Self::AnswerOne> {
+        const MAX_DIRECTORY_SIZE: u32 = 100_000;
+
+        let dirs = dirs_with_size_constraint(file_system.clone(), |size| {
+            size <= MAX_DIRECTORY_SIZE
+        });
+
+        Some(dirs.iter().map(|dir| dir.borrow().total_size).sum())
+    }
+
+    fn part_two(file_system: &Self::Input<'_>) -> Option<Self::AnswerTwo> {
+        const DISK_SPACE: u32 = 70_000_000;
+        const REQUIRED_UNUSED_SPACE: u32 = 30000000;
+
+        // No directory can be deleted to make room if the files don't even fit on the disk
+        let remaining = DISK_SPACE.checked_sub(file_system.borrow().total_size)?;
+        let required_dir_size = REQUIRED_UNUSED_SPACE.saturating_sub(remaining);
+        let dirs = dirs_with_size_constraint(file_system.clone(), |size| {
+            size >= required_dir_size
+        });
+
+        dirs.iter().map(|d| d.borrow().total_size).min()
+    }
+}
+
+fn construct_file_system(input: &str) -> Result<Rc<RefCell<Directory>>, SolveError> {
+    // The root '/'
+    let file_system = Rc::new(RefCell::new(Directory::empty_with_parent(None)));
+
+    // The current directory
+    let mut curr_dir = file_system.clone();
+
+    for line in input.lines() {
+        let parts: Vec<&str> = line.split_whitespace().collect();
+        // Missing parts are pointed to at the end of the line
+        let part = |idx: usize, expected: &str| {
+            parts
+                .get(idx)
+                .copied()
+                .ok_or_else(|| SolveError::new(format!("expected {expected}"), &line[line.len()..]))
+        };
+
+        if part(0, "a command or a directory listing")? == "$" {
+            let command = part(1, "a command")?;
+
+            if command == "cd" {
+                let path = part(2, "a directory to change to")?;
+                let old_dir = curr_dir.clone();
+
+                curr_dir = if path == "/" {
+                    file_system.clone()
+                } else if path == ".." {
+                    let parent = old_dir.borrow().parent.clone();
+                    parent.ok_or_else(|| SolveError::new("can't leave the root directory", path))?
+                } else {
+                    let sub_dir = old_dir.borrow().sub_directories.get(path).cloned();
+                    sub_dir.ok_or_else(|| SolveError::new("unknown directory", path))?
+                };
+            }
+        } else if parts[0] == "dir" {
+            let name = part(1, "a directory name")?.to_string();
+            let old_dir = curr_dir.clone();
+            let new_dir = Rc::new(RefCell::new(Directory::empty_with_parent(Some(old_dir))));
+            curr_dir.borrow_mut().sub_directories.insert(name, new_dir);
+        } else {
+            let file_size = utils::parse_number(parts[0])?;
+            curr_dir.borrow_mut().add_file_size(file_size);
+        }
+    }
+
+    Ok(file_system)
+}
+
+fn dirs_with_size_constraint<F: FnOnce(u32) -> bool + Clone + Copy>(
+    dir: Rc<RefCell<Directory>>,
+    constraint: F,
+) -> Vec<Rc<RefCell<Directory>>> {
+    let mut dirs = vec![];
+    for sub_dir in dir.borrow().sub_directories.values() {
+        if constraint(sub_dir.borrow().total_size) {
+            dirs.push(sub_dir.clone());
+        }
+        dirs.extend(dirs_with_size_constraint(sub_dir.clone(), constraint));
+    }
+    dirs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_INPUT: &str = include_str!("example.txt");
+
+    #[test]
+    fn test_part_one() {
+        assert_eq!(Puzzle::solve_part_one(EXAMPLE_INPUT), Some(95437));
+    }
+
+    #[test]
+    fn test_part_two() {
+        assert_eq!(Puzzle::solve_part_two(EXAMPLE_INPUT), Some(24933642));
+        assert_eq!(Puzzle::solve_part_two("$ cd /\n$ ls\n80000000 a.txt"), None);
+    }
+}
@@ -0,0 +1,119 @@
+use crate::{error::SolveError, solution::Solution};
+
+/// Tree heights, either as rows or as columns.
+type Grid = Vec<Vec<u8>>;
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Treetop Tree House";
+
+    type Input<'a> = (Grid, Grid);
+    type AnswerOne = u32;
+    type AnswerTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, SolveError> {
+        parse_input(input)
+    }
+
+    fn part_one((rows, columns): &Self::Input<'_>) -> Option<Self::AnswerOne> {
+        let mut visible_count = 0;
+
+        for (y, row) in rows.iter().enumerate() {
+            for (x, tree_height) in row.iter().enumerate() {
+                let condition = |height| height < tree_height;
+                let visible_top = columns[x].iter().take(y).all(condition);
+                let visible_bottom = columns[x].iter().skip(y + 1).all(condition);
+                let visible_left = row.iter().take(x).all(condition);
+                let visible_right = row.iter().skip(x + 1).all(condition);
+
+                if visible_top || visible_bottom || visible_left || visible_right {
+                    visible_count += 1;
+                }
+            }
+        }
+
+        Some(visible_count)
+    }
+
+    fn part_two((rows, columns): &Self::Input<'_>) -> Option<Self::AnswerTwo> {
+        let mut best_scenic_score = 0;
+
+        for (y, row) in rows.iter().enumerate() {
+            for (x, tree_height) in row.iter().enumerate() {
+                let view_up = get_visible_count(columns[x].iter().take(y).rev(), *tree_height);
+                let view_bottom = get_visible_count(columns[x].iter().skip(y + 1), *tree_height);
+                let view_left = get_visible_count(row.iter().take(x).rev(), *tree_height);
+                let view_right = get_visible_count(row.iter().skip(x + 1), *tree_height);
+
+                let scenic_score = (view_up * view_bottom * view_left * view_right) as u32;
+                if scenic_score > best_scenic_score {
+                    best_scenic_score = scenic_score;
+                }
+            }
+        }
+
+        Some(best_scenic_score)
+    }
+}
+
+fn parse_input(input: &str) -> Result<(Grid, Grid), SolveError> {
+    let width = input.lines().next().map_or(0, str::len);
+    let rows = input
+        .lines()
+        .map(|line| {
+            if line.len() != width {
+                return Err(SolveError::new(
+                    format!("expected a row of {width} trees"),
+                    line,
+                ));
+            }
+            line_to_digits(line)
+        })
+        .collect::<Result<Grid, SolveError>>()?;
+    let columns: Grid = (0..width).map(|x| get_column(&rows, x)).collect();
+    Ok((rows, columns))
+}
+
+fn line_to_digits(line: &str) -> Result<Vec<u8>, SolveError> {
+    line.char_indices()
+        .map(|(idx, c)| {
+            c.to_digit(10)
+                .map(|digit| digit as u8)
+                .ok_or_else(|| SolveError::new("expected a digit", &line[idx..idx + c.len_utf8()]))
+        })
+        .collect()
+}
+
+fn get_visible_count<'a, I: Iterator<Item = &'a u8>>(row: I, max_height: u8) -> usize {
+    let mut viewing_distance = 0;
+    for height in row {
+        viewing_distance += 1;
+        if *height >= max_height {
+            break;
+        }
+    }
+    viewing_distance
+}
+
+fn get_column<T: Clone + Copy>(table: &[Vec<T>], index: usize) -> Vec<T> {
+    table.iter().map(|row| row[index]).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_INPUT: &str = include_str!("example.txt");
+
+    #[test]
+    fn test_part_one() {
+        assert_eq!(Puzzle::solve_part_one(EXAMPLE_INPUT), Some(21));
+    }
+
+    #[test]
+    fn test_part_two() {
+        assert_eq!(Puzzle::solve_part_two(EXAMPLE_INPUT), Some(8));
+    }
+}
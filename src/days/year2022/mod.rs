@@ -0,0 +1,11 @@
+days! {
+    2022:
+    day01,
+    day02,
+    day03,
+    day04,
+    day05,
+    day06,
+    day07,
+    day08,
+}
@@ -0,0 +1,112 @@
+use std::{error::Error, fmt::Display};
+
+/// An error in a puzzle input that a solution couldn't make sense of.
+///
+/// The error points to the offending text in the input. It's created from a slice of the input, and once the runner
+/// has the error, it finds the line and column of the slice with [`SolveError::locate`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SolveError {
+    pub message: String,
+    /// The text in the input that caused the error.
+    pub text: String,
+    /// Where the offending text is in the input, if it has been located.
+    pub location: Option<Location>,
+    /// The address of the offending text, used to find it in the input it was sliced from.
+    address: usize,
+}
+
+/// The position of an error's offending text in the input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Location {
+    /// The line number, starting from 1.
+    pub line: usize,
+    /// The column in characters, starting from 1.
+    pub column: usize,
+    /// The whole line that the offending text is on.
+    pub source_line: String,
+}
+
+impl SolveError {
+    /// Creates an error about some offending text, which should be a slice of the input so that it can be located.
+    ///
+    /// For missing text, an empty slice where the text should have been can be used.
+    ///
+    /// The text is located by its address, so an error about a string that was built from the input rather than sliced
+    /// from it, e.g. with `format!` or `replace`, can't be located and is reported without a line and column.
+    pub fn new(message: impl Into<String>, text: &str) -> Self {
+        Self {
+            message: message.into(),
+            text: text.to_string(),
+            location: None,
+            address: text.as_ptr() as usize,
+        }
+    }
+
+    /// Finds the location of the offending text in the input it was sliced from.
+    ///
+    /// If the text isn't a slice of the given input, the error is left without a location.
+    pub fn locate(mut self, input: &str) -> Self {
+        let start = input.as_ptr() as usize;
+        let Some(offset) = self.address.checked_sub(start) else {
+            return self;
+        };
+        if offset > input.len() || !input.is_char_boundary(offset) {
+            return self;
+        }
+
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |idx| offset + idx);
+        self.location = Some(Location {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            source_line: input[line_start..line_end].to_string(),
+        });
+        self
+    }
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)?;
+        if !self.text.is_empty() {
+            write!(f, ": `{}`", self.text)?;
+        }
+        if let Some(location) = &self.location {
+            write!(f, " at line {}, column {}", location.line, location.column)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for SolveError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_locate() {
+        let input = "2-4,6-8\n2-x,4-5";
+        let err = SolveError::new("expected a number", &input[10..11]).locate(input);
+
+        assert_eq!(
+            err.location,
+            Some(Location {
+                line: 2,
+                column: 3,
+                source_line: "2-x,4-5".to_string(),
+            })
+        );
+        assert_eq!(
+            err.to_string(),
+            "expected a number: `x` at line 2, column 3"
+        );
+
+        // Text that isn't from the input can't be located
+        let err = SolveError::new("expected a number", "x").locate(input);
+        assert_eq!(err.location, None);
+    }
+}
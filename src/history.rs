@@ -0,0 +1,440 @@
+use std::{
+    fs,
+    io::{self, Write},
+    path::Path,
+    process::Command,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use toml::{Table, Value};
+
+use crate::runner::{DayResult, PartStatus};
+
+/// How much slower a part has to be than its baseline to be flagged, as a fraction of the baseline.
+/// This keeps tiny but statistically significant slowdowns from being flagged.
+const MIN_SLOWDOWN: f64 = 0.05;
+/// The t-statistic above which a slowdown is significant, which is about 99% confidence for a one-sided test.
+const MIN_T_STATISTIC: f64 = 2.33;
+
+/// The run time of a part, either from a single run or from the statistics of a benchmark.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Sample {
+    pub mean: Duration,
+    pub std_dev: Duration,
+    pub runs: usize,
+}
+
+/// The run time of one part of a day for one of its inputs.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartTiming {
+    pub year: u16,
+    pub day: u8,
+    pub input: Option<String>,
+    pub part: u8,
+    pub sample: Sample,
+}
+
+/// A run of the solutions along with the environment it was run in.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HistoryEntry {
+    /// When the run happened, in UTC, e.g. `2022-12-08T18:30:00Z`.
+    pub date: String,
+    pub commit: Option<String>,
+    /// The version of the compiler that built the runner.
+    pub rustc: Option<String>,
+    /// The build profile of the runner, `debug` or `release`, since debug builds are much slower.
+    pub profile: Option<String>,
+    pub cpu: Option<String>,
+    pub parts: Vec<PartTiming>,
+}
+
+impl HistoryEntry {
+    /// Creates an entry for the current environment with the run times of the parts that finished.
+    pub fn new(results: &[DayResult]) -> Self {
+        let parts = results
+            .iter()
+            .flat_map(|res| {
+                res.parts().into_iter().filter_map(|(part, part_res)| {
+                    let finished = matches!(
+                        part_res.status(),
+                        PartStatus::Solved | PartStatus::Incorrect
+                    );
+                    let sample = match &part_res.timing.stats {
+                        Some(stats) => Sample {
+                            mean: stats.mean,
+                            std_dev: stats.std_dev,
+                            runs: stats.runs,
+                        },
+                        None => Sample {
+                            mean: part_res.timing.duration,
+                            std_dev: Duration::ZERO,
+                            runs: 1,
+                        },
+                    };
+                    finished.then(|| PartTiming {
+                        year: res.year,
+                        day: res.day,
+                        input: res.input.clone(),
+                        part,
+                        sample,
+                    })
+                })
+            })
+            .collect();
+
+        Self {
+            date: format_date(SystemTime::now()),
+            // The commit of the crate, rather than of whatever repository the runner is run from
+            commit: command_output(
+                "git",
+                &[
+                    "-C",
+                    env!("CARGO_MANIFEST_DIR"),
+                    "rev-parse",
+                    "--short",
+                    "HEAD",
+                ],
+            ),
+            rustc: option_env!("AOC_RUSTC_VERSION").map(str::to_string),
+            profile: Some(current_profile().to_string()),
+            cpu: cpu_model(),
+            parts,
+        }
+    }
+
+    /// Checks if the run times of another entry can be compared with this one's, which needs the same build profile
+    /// and CPU.
+    pub fn is_comparable(&self, other: &HistoryEntry) -> bool {
+        self.profile.is_some() && self.profile == other.profile && self.cpu == other.cpu
+    }
+
+    /// Finds the run time of a given part, if it was run.
+    pub fn get(&self, year: u16, day: u8, input: Option<&str>, part: u8) -> Option<&PartTiming> {
+        self.parts.iter().find(|timing| {
+            timing.year == year
+                && timing.day == day
+                && timing.input.as_deref() == input
+                && timing.part == part
+        })
+    }
+
+    fn to_toml(&self) -> Table {
+        let mut entry = Table::new();
+        entry.insert("date".into(), self.date.clone().into());
+        for (key, value) in [
+            ("commit", &self.commit),
+            ("rustc", &self.rustc),
+            ("profile", &self.profile),
+            ("cpu", &self.cpu),
+        ] {
+            if let Some(value) = value {
+                entry.insert(key.into(), value.clone().into());
+            }
+        }
+
+        let parts = self
+            .parts
+            .iter()
+            .map(|timing| {
+                let mut part = Table::new();
+                part.insert("year".into(), i64::from(timing.year).into());
+                part.insert("day".into(), i64::from(timing.day).into());
+                if let Some(input) = &timing.input {
+                    part.insert("input".into(), input.clone().into());
+                }
+                part.insert("part".into(), i64::from(timing.part).into());
+                part.insert("mean_ns".into(), nanos(timing.sample.mean).into());
+                part.insert("std_dev_ns".into(), nanos(timing.sample.std_dev).into());
+                part.insert("runs".into(), (timing.sample.runs as i64).into());
+                Value::Table(part)
+            })
+            .collect::<Vec<Value>>();
+        entry.insert("parts".into(), parts.into());
+        entry
+    }
+
+    fn from_toml(entry: &Table) -> Option<Self> {
+        let string = |key| entry.get(key)?.as_str().map(str::to_string);
+        let parts = entry
+            .get("parts")
+            .and_then(Value::as_array)
+            .map_or(&[][..], Vec::as_slice)
+            .iter()
+            .filter_map(|part| {
+                let int = |key| part.get(key)?.as_integer();
+                let duration = |key| int(key).map(|nanos| Duration::from_nanos(nanos as u64));
+                Some(PartTiming {
+                    // Runs from before years were recorded only had solutions from 2022
+                    year: int("year").unwrap_or(2022) as u16,
+                    day: int("day")? as u8,
+                    input: part
+                        .get("input")
+                        .and_then(Value::as_str)
+                        .map(str::to_string),
+                    part: int("part")? as u8,
+                    sample: Sample {
+                        mean: duration("mean_ns")?,
+                        std_dev: duration("std_dev_ns").unwrap_or_default(),
+                        runs: int("runs").unwrap_or(1) as usize,
+                    },
+                })
+            })
+            .collect();
+
+        Some(Self {
+            date: string("date")?,
+            commit: string("commit"),
+            rustc: string("rustc"),
+            profile: string("profile"),
+            cpu: string("cpu"),
+            parts,
+        })
+    }
+}
+
+/// Loads the previous runs from a history file, oldest first.
+///
+/// If the file doesn't exist, there is no history and this returns an empty list.
+pub fn load(path: &Path) -> Result<Vec<HistoryEntry>, Box<dyn std::error::Error>> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(err) => return Err(err.into()),
+    };
+    let table = content.parse::<Table>()?;
+    let entries = table
+        .get("run")
+        .and_then(Value::as_array)
+        .map_or(&[][..], Vec::as_slice)
+        .iter()
+        .filter_map(|entry| HistoryEntry::from_toml(entry.as_table()?))
+        .collect();
+    Ok(entries)
+}
+
+/// Appends a run to a history file, creating the file if it doesn't exist.
+///
+/// Each run is its own `[[run]]` table, so the file never has to be rewritten.
+pub fn append(path: &Path, entry: &HistoryEntry) -> io::Result<()> {
+    let mut table = Table::new();
+    table.insert("run".into(), vec![Value::Table(entry.to_toml())].into());
+
+    let mut file = fs::File::options().create(true).append(true).open(path)?;
+    writeln!(file, "{table}")
+}
+
+/// A part that got slower than its baseline.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Regression {
+    pub year: u16,
+    pub day: u8,
+    pub input: Option<String>,
+    pub part: u8,
+    pub baseline: Sample,
+    pub current: Sample,
+}
+
+/// Compares the parts of a run against the most recent earlier benchmark of each part with the same build profile
+/// and CPU, and returns the ones that got slower.
+///
+/// A part has to be significantly slower according to Welch's t-test. Parts that weren't benchmarked are never
+/// flagged, since a single run says nothing about the noise.
+pub fn find_regressions(history: &[HistoryEntry], current: &HistoryEntry) -> Vec<Regression> {
+    current
+        .parts
+        .iter()
+        .filter_map(|timing| {
+            let baseline = history
+                .iter()
+                .rev()
+                .filter(|entry| entry.is_comparable(current))
+                .filter_map(|entry| {
+                    entry.get(
+                        timing.year,
+                        timing.day,
+                        timing.input.as_deref(),
+                        timing.part,
+                    )
+                })
+                .find(|baseline| baseline.sample.runs >= 2)?;
+            is_slower(&timing.sample, &baseline.sample).then(|| Regression {
+                year: timing.year,
+                day: timing.day,
+                input: timing.input.clone(),
+                part: timing.part,
+                baseline: baseline.sample,
+                current: timing.sample,
+            })
+        })
+        .collect()
+}
+
+/// Checks if a sample is slower than a baseline beyond what can be explained by noise.
+fn is_slower(current: &Sample, baseline: &Sample) -> bool {
+    let (mean, baseline_mean) = (
+        current.mean.as_nanos() as f64,
+        baseline.mean.as_nanos() as f64,
+    );
+    let slowdown = (mean - baseline_mean) / baseline_mean.max(1.0);
+
+    if current.runs < 2 || baseline.runs < 2 {
+        return false;
+    }
+
+    let variance =
+        |sample: &Sample| (sample.std_dev.as_nanos() as f64).powi(2) / sample.runs as f64;
+    let std_error = (variance(current) + variance(baseline)).sqrt();
+    if std_error == 0.0 {
+        return slowdown > MIN_SLOWDOWN;
+    }
+    let t = (mean - baseline_mean) / std_error;
+    slowdown > MIN_SLOWDOWN && t > MIN_T_STATISTIC
+}
+
+/// The build profile the runner was built with.
+fn current_profile() -> &'static str {
+    if cfg!(debug_assertions) {
+        "debug"
+    } else {
+        "release"
+    }
+}
+
+fn nanos(duration: Duration) -> i64 {
+    duration.as_nanos().try_into().unwrap_or(i64::MAX)
+}
+
+/// Runs a command and returns the first line of its output, if it succeeded.
+fn command_output(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program).args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let stdout = String::from_utf8(output.stdout).ok()?;
+    stdout.lines().next().map(|line| line.trim().to_string())
+}
+
+/// Gets the model of the CPU from `/proc/cpuinfo`, which only exists on Linux.
+fn cpu_model() -> Option<String> {
+    let cpuinfo = fs::read_to_string("/proc/cpuinfo").ok()?;
+    cpuinfo.lines().find_map(|line| {
+        let (key, value) = line.split_once(':')?;
+        (key.trim() == "model name").then(|| value.trim().to_string())
+    })
+}
+
+/// Formats a time as an ISO 8601 date and time in UTC, e.g. `2022-12-08T18:30:00Z`.
+fn format_date(time: SystemTime) -> String {
+    let secs = time
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let (days, secs) = (secs / 86_400, secs % 86_400);
+
+    // Converts days since the epoch to a civil date, from http://howardhinnant.github.io/date_algorithms.html
+    let z = days as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        secs / 3600,
+        secs / 60 % 60,
+        secs % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(mean_micros: u64, std_dev_micros: u64, runs: usize) -> Sample {
+        Sample {
+            mean: Duration::from_micros(mean_micros),
+            std_dev: Duration::from_micros(std_dev_micros),
+            runs,
+        }
+    }
+
+    #[test]
+    fn test_format_date() {
+        assert_eq!(format_date(UNIX_EPOCH), "1970-01-01T00:00:00Z");
+        let time = UNIX_EPOCH + Duration::from_secs(1_670_524_200);
+        assert_eq!(format_date(time), "2022-12-08T18:30:00Z");
+    }
+
+    #[test]
+    fn test_is_slower() {
+        // Slower, but within the noise
+        assert!(!is_slower(&sample(105, 20, 10), &sample(100, 20, 10)));
+        assert!(is_slower(&sample(150, 5, 10), &sample(100, 5, 10)));
+        assert!(!is_slower(&sample(90, 5, 10), &sample(100, 5, 10)));
+        // Single runs say nothing about the noise
+        assert!(!is_slower(&sample(200, 0, 1), &sample(100, 5, 10)));
+        assert!(!is_slower(&sample(200, 0, 1), &sample(100, 0, 1)));
+    }
+
+    #[test]
+    fn test_find_regressions() {
+        let entry = |profile: &str, mean_micros| HistoryEntry {
+            date: "2022-12-08T18:30:00Z".to_string(),
+            commit: None,
+            rustc: None,
+            profile: Some(profile.to_string()),
+            cpu: Some("Some CPU".to_string()),
+            parts: vec![PartTiming {
+                year: 2022,
+                day: 1,
+                input: None,
+                part: 1,
+                sample: sample(mean_micros, 5, 10),
+            }],
+        };
+        let current = entry("release", 150);
+
+        // The debug run is more recent, but can't be compared with a release run
+        let history = [entry("release", 100), entry("debug", 1000)];
+        assert_eq!(find_regressions(&history, &current).len(), 1);
+        assert!(find_regressions(&history[1..], &current).is_empty());
+
+        let mut other_cpu = entry("release", 100);
+        other_cpu.cpu = Some("Another CPU".to_string());
+        assert!(find_regressions(&[other_cpu], &current).is_empty());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let entry = HistoryEntry {
+            date: "2022-12-08T18:30:00Z".to_string(),
+            commit: Some("abc1234".to_string()),
+            rustc: None,
+            profile: Some("release".to_string()),
+            cpu: Some("Some CPU".to_string()),
+            parts: vec![PartTiming {
+                year: 2022,
+                day: 8,
+                input: Some("alice".to_string()),
+                part: 2,
+                sample: sample(150, 5, 10),
+            }],
+        };
+
+        let mut table = Table::new();
+        table.insert("run".into(), vec![Value::Table(entry.to_toml())].into());
+        let text = format!("{table}\n{table}");
+        let parsed = text.parse::<Table>().unwrap();
+        let runs = parsed["run"].as_array().unwrap();
+
+        assert_eq!(runs.len(), 2);
+        assert_eq!(
+            HistoryEntry::from_toml(runs[1].as_table().unwrap()),
+            Some(entry)
+        );
+    }
+}
@@ -0,0 +1,208 @@
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+/// The environment variable that overrides the directory the inputs are read from.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// An input along with its name and the file it was read from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Input {
+    /// The name of the input, or [`None`] for a day's main input.
+    pub name: Option<String>,
+    /// The file the input was read from, or `-` for stdin.
+    pub path: PathBuf,
+    pub text: String,
+}
+
+/// A directory with a `dayNN` subdirectory for each day, which has the day's inputs and examples.
+///
+/// The inputs of each year are in a `yearNNNN` subdirectory of the root input directory, see [`InputDir::year`].
+/// By default, the root is the crate's `src/days` directory, so that inputs are found no matter where the binary is run from.
+/// The examples are source files that the tests include, so they are always read from [`InputDir::source`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InputDir(PathBuf);
+
+impl InputDir {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self(path.into())
+    }
+
+    /// Gets the root input directory from the [`INPUT_DIR_VAR`] environment variable,
+    /// or falls back to the `src/days` directory of the crate.
+    pub fn from_env() -> Self {
+        match env::var_os(INPUT_DIR_VAR) {
+            Some(path) => Self::new(path),
+            None => Self::source(),
+        }
+    }
+
+    /// The crate's `src/days` directory, next to the solutions, where the examples are.
+    pub fn source() -> Self {
+        Self::new(Path::new(env!("CARGO_MANIFEST_DIR")).join("src/days"))
+    }
+
+    /// The input directory of a given year, e.g. `src/days/year2022`.
+    pub fn year(&self, year: u16) -> Self {
+        Self(self.0.join(format!("year{year}")))
+    }
+
+    /// The directory of a given day, e.g. `src/days/year2022/day07`.
+    pub fn day_dir(&self, day: u8) -> PathBuf {
+        self.0.join(format!("day{day:02}"))
+    }
+
+    /// The path of a given day's main input.
+    pub fn input_path(&self, day: u8) -> PathBuf {
+        self.day_dir(day).join("input.txt")
+    }
+
+    /// Reads a given day's main input from its `input.txt`.
+    pub fn read_input(&self, day: u8) -> io::Result<String> {
+        let path = self.input_path(day);
+        fs::read_to_string(&path).map_err(|err| with_path(err, &path))
+    }
+
+    /// Reads every input of a given day along with its name.
+    ///
+    /// The inputs are the day's `input.txt`, which has no name, and the files in its `inputs` directory,
+    /// which are named after the file, e.g. `alice` for `inputs/alice.txt`. Only `.txt` files are inputs.
+    pub fn read_inputs(&self, day: u8) -> io::Result<Vec<Input>> {
+        let mut inputs = vec![];
+        match self.read_input(day) {
+            Ok(text) => inputs.push(Input {
+                name: None,
+                path: self.input_path(day),
+                text,
+            }),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+            Err(err) => return Err(err),
+        }
+
+        let dir = self.day_dir(day).join("inputs");
+        // Other files, like a `.gitkeep`, a README or an editor's swap files, aren't inputs
+        inputs.extend(read_named_inputs(&dir, |file_name| {
+            file_name.ends_with(".txt")
+        })?);
+
+        if inputs.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!(
+                    "no input at {} or in {}",
+                    self.input_path(day).display(),
+                    dir.display()
+                ),
+            ));
+        }
+        Ok(inputs)
+    }
+
+    /// Reads the example inputs of a given day, i.e. its `example.txt` and other files like `example2.txt`,
+    /// named after the file. Examples should be read from the [`InputDir::source`] directory.
+    pub fn read_examples(&self, day: u8) -> io::Result<Vec<Input>> {
+        let dir = self.day_dir(day);
+        let examples = read_named_inputs(&dir, |file_name| {
+            file_name.starts_with("example") && file_name.ends_with(".txt")
+        })?;
+
+        if examples.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("no examples in {}", dir.display()),
+            ));
+        }
+        Ok(examples)
+    }
+}
+
+/// Reads an input from a given file, or from stdin if the path is `-`.
+pub fn read_input_file(path: &Path) -> io::Result<String> {
+    let input = if path == Path::new("-") {
+        io::read_to_string(io::stdin())?
+    } else {
+        fs::read_to_string(path).map_err(|err| with_path(err, path))?
+    };
+
+    // Fetched inputs have no trailing newline, but inputs from editors and pipes usually do
+    Ok(input.trim_end_matches(['\n', '\r']).to_string())
+}
+
+/// Reads the files in a directory whose names match a filter, sorted by name. Each input is named after its file.
+///
+/// A missing directory has no inputs.
+fn read_named_inputs(dir: &Path, filter: impl Fn(&str) -> bool) -> io::Result<Vec<Input>> {
+    let mut paths = match fs::read_dir(dir) {
+        Ok(entries) => entries
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<io::Result<Vec<PathBuf>>>()?,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => vec![],
+        Err(err) => return Err(with_path(err, dir)),
+    };
+    paths.sort();
+
+    let mut inputs = vec![];
+    for path in paths.iter().filter(|path| path.is_file()) {
+        let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+        if !filter(&file_name) {
+            continue;
+        }
+        let name = path.file_stem().unwrap_or_default().to_string_lossy();
+        inputs.push(Input {
+            name: Some(name.to_string()),
+            path: path.clone(),
+            text: read_input_file(path)?,
+        });
+    }
+    Ok(inputs)
+}
+
+/// Adds the path of a file to an IO error, since the errors from [`fs`] don't say which file they're about.
+fn with_path(err: io::Error, path: &Path) -> io::Error {
+    io::Error::new(err.kind(), format!("{}: {err}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_examples() {
+        let dir = InputDir::source().year(2022);
+        let examples = dir.read_examples(6).unwrap();
+        let names = examples.iter().map(|example| example.name.as_deref());
+
+        assert!(names.eq([
+            Some("example"),
+            Some("example2"),
+            Some("example3"),
+            Some("example4"),
+            Some("example5")
+        ]));
+        assert_eq!(examples[1].text, "bvwbjplbgvbhsrlpgdmjqwftvncz");
+        assert!(examples[1].path.ends_with("year2022/day06/example2.txt"));
+
+        let err = InputDir::new("missing").read_inputs(1).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn test_read_inputs() {
+        let root = env::temp_dir().join(format!("aoc_inputs_{}", std::process::id()));
+        let inputs_dir = root.join("day01/inputs");
+        fs::create_dir_all(&inputs_dir).unwrap();
+        for file_name in ["alice.txt", ".gitkeep", "README.md", ".alice.txt.swp"] {
+            fs::write(inputs_dir.join(file_name), "1000").unwrap();
+        }
+
+        let inputs = InputDir::new(&root).read_inputs(1);
+        fs::remove_dir_all(&root).unwrap();
+        let names: Vec<_> = inputs
+            .unwrap()
+            .into_iter()
+            .map(|input| input.name)
+            .collect();
+        assert_eq!(names, [Some("alice".to_string())]);
+    }
+}
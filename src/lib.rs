@@ -0,0 +1,17 @@
+//! Solutions to [Advent of Code](https://adventofcode.com) puzzles from several years, along with the runner that solves, times and verifies them.
+//!
+//! The `aoc_2022` binary is a command line interface on top of this library, and the `scaffold` binary generates new days.
+
+pub mod alloc;
+pub mod answer;
+pub mod answers;
+pub mod bench;
+pub mod days;
+pub mod error;
+pub mod history;
+pub mod inputs;
+pub mod output;
+pub mod readme;
+pub mod runner;
+pub mod solution;
+pub mod utils;
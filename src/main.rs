@@ -1,99 +1,352 @@
-mod days;
-mod utils;
-
-use days::*;
+use aoc_2022::{
+    answers::Answers,
+    days, history,
+    inputs::InputDir,
+    output::{OutputFormat, Reporter},
+    readme,
+    runner::{self, InputSource, PartSelection, RunOptions, RunStatus, YearRunner},
+};
 use std::{
-    fmt::Display,
-    fs,
-    time::{Duration, Instant},
+    ops::RangeInclusive,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    thread,
+    time::Duration,
 };
 
-use clap::Parser;
-use colored::Colorize;
+use clap::{error::ErrorKind, ArgGroup, CommandFactory, Parser};
+
+#[cfg(feature = "count-allocs")]
+#[global_allocator]
+static ALLOCATOR: aoc_2022::alloc::CountingAllocator = aoc_2022::alloc::CountingAllocator;
 
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
+#[command(group = ArgGroup::new("single_day").args(["day", "latest"]))]
 struct Args {
+    /// Year to run. Defaults to the latest year
+    #[arg(short, long, value_parser = parse_year)]
+    year: Option<u16>,
+
     /// Day to run
-    #[arg(short, long)]
+    #[arg(short, long, value_parser = parse_day, conflicts_with_all = ["days", "latest"])]
     day: Option<u8>,
-}
 
-struct DayResult {
-    part_one_solution: String,
-    part_two_solution: String,
-    part_one_duration: Duration,
-    part_two_duration: Duration,
+    /// Days to run, e.g. `1,3,5-8`
+    #[arg(long, value_delimiter = ',', value_parser = parse_day_range, conflicts_with = "latest")]
+    days: Vec<RangeInclusive<u8>>,
+
+    /// Run the latest solved day of the year
+    #[arg(short, long)]
+    latest: bool,
+
+    /// Part to run
+    #[arg(short, long, value_enum, default_value_t = PartSelection::Both)]
+    part: PartSelection,
+
+    /// File to read the day's input from instead of its `input.txt`. Use `-` to read from stdin, except with `--watch`
+    #[arg(short, long, value_name = "PATH", requires = "single_day")]
+    input: Option<PathBuf>,
+
+    /// Directory with a `yearNNNN/dayNN` directory of inputs for each day.
+    /// Defaults to the `AOC_INPUT_DIR` environment variable, or the crate's `src/days` directory
+    #[arg(long, value_name = "DIR")]
+    input_dir: Option<PathBuf>,
+
+    /// Solve the day's examples (`example.txt`, `example2.txt`, ...) instead of its real inputs
+    #[arg(short, long, conflicts_with = "input")]
+    example: bool,
+
+    /// Benchmark the solutions by running each part a given number of times
+    #[arg(short, long, value_name = "N", value_parser = clap::value_parser!(u32).range(1..))]
+    bench: Option<u32>,
+
+    /// Time limit for each part, e.g. `500ms`, `10s` or `2m`. Parts that exceed it are marked as timed out
+    #[arg(short, long, value_name = "DURATION", value_parser = parse_duration)]
+    timeout: Option<Duration>,
+
+    /// Format to print the results in
+    #[arg(short, long, value_enum, default_value_t = OutputFormat::Text)]
+    format: OutputFormat,
+
+    /// Only print the answers, one per line. Short for `--format answers`
+    #[arg(short, long, conflicts_with = "format")]
+    quiet: bool,
+
+    /// File with the expected answers to verify the solutions against [default: `answers.toml` in the crate root]
+    #[arg(short, long, value_name = "PATH")]
+    answers: Option<PathBuf>,
+
+    /// How many days to solve in parallel when running all days. 0 uses all available CPUs
+    #[arg(short, long, value_name = "N", default_value_t = 1)]
+    jobs: usize,
+
+    /// Update the rows of the days that were run in the results table of the crate's `README.md`
+    #[arg(long, conflicts_with_all = ["example", "input", "part"])]
+    update_readme: bool,
+
+    /// File that the run times of each run are appended to [default: `perf-history.toml` in the crate root]
+    #[arg(long, value_name = "PATH")]
+    history: Option<PathBuf>,
+
+    /// Flag parts that got significantly slower than in the previous benchmark recorded in the history
+    #[arg(long, requires = "bench", conflicts_with = "input")]
+    compare: bool,
+
+    /// Re-run the day whenever its input or `example.txt` changes
+    #[arg(short, long, requires = "single_day", conflicts_with_all = ["update_readme", "example"])]
+    watch: bool,
 }
 
-type PuzzleSolver<T> = fn(&str) -> Option<T>;
-
-impl DayResult {
-    fn from_solvers<T: Display>(
-        input: &str,
-        part_one: PuzzleSolver<T>,
-        part_two: PuzzleSolver<T>,
-    ) -> Self {
-        let time_one = Instant::now();
-        let part_one_solution = part_one(input);
-        let part_one_duration = time_one.elapsed();
-
-        let time_two = Instant::now();
-        let part_two_solution = part_two(input);
-        let part_two_duration = time_two.elapsed();
-
-        Self {
-            part_one_solution: part_one_solution.map_or("-".to_string(), |val| val.to_string()),
-            part_two_solution: part_two_solution.map_or("-".to_string(), |val| val.to_string()),
-            part_one_duration,
-            part_two_duration,
+impl Args {
+    /// Parses the arguments, exiting like clap does if they can't be used together.
+    fn parse_checked() -> Self {
+        let args = Self::parse();
+        if args.watch && args.input.as_deref() == Some(Path::new("-")) {
+            Self::command()
+                .error(
+                    ErrorKind::ArgumentConflict,
+                    "stdin can't be watched for changes, so `--input -` can't be used with `--watch`",
+                )
+                .exit();
         }
+        args
     }
-}
 
-fn get_day_result(input: &str, day: u8) -> DayResult {
-    match day {
-        1 => DayResult::from_solvers(input, day01::part_one, day01::part_two),
-        2 => DayResult::from_solvers(input, day02::part_one, day02::part_two),
-        3 => DayResult::from_solvers(input, day03::part_one, day03::part_two),
-        4 => DayResult::from_solvers(input, day04::part_one, day04::part_two),
-        5 => DayResult::from_solvers(input, day05::part_one, day05::part_two),
-        6 => DayResult::from_solvers(input, day06::part_one, day06::part_two),
-        7 => DayResult::from_solvers(input, day07::part_one, day07::part_two),
-        8 => DayResult::from_solvers(input, day08::part_one, day08::part_two),
-        _ => panic!("Couldn't run day {day}. "),
+    /// The year to run, which is the latest year unless one is given.
+    fn year(&self) -> u16 {
+        self.year.unwrap_or_else(days::latest_year)
+    }
+
+    /// The format to print the results in, taking `--quiet` into account.
+    fn output_format(&self) -> OutputFormat {
+        if self.quiet {
+            OutputFormat::Answers
+        } else {
+            self.format
+        }
+    }
+
+    /// The directory to read the year's inputs from, which doesn't depend on the current working directory
+    /// unless it's given as a relative path.
+    fn input_dir(&self) -> InputDir {
+        let root = match &self.input_dir {
+            Some(dir) => InputDir::new(dir),
+            None => InputDir::from_env(),
+        };
+        root.year(self.year())
     }
-}
 
-fn run_day(day: u8) -> std::io::Result<String> {
-    let input = fs::read_to_string(format!("src/days/day{:02}/input.txt", day))?;
-    let res = get_day_result(&input, day);
+    /// Where to read the inputs of each day from.
+    fn input_source(&self) -> InputSource {
+        match &self.input {
+            Some(path) => InputSource::File(path.clone()),
+            None if self.example => InputSource::Examples,
+            None => InputSource::Inputs(self.input_dir()),
+        }
+    }
+
+    /// The file with the expected answers, which defaults to the crate root rather than the current working directory.
+    fn answers_path(&self) -> PathBuf {
+        self.answers
+            .clone()
+            .unwrap_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).join("answers.toml"))
+    }
 
-    println!("{}", format!("Day {day}").bold().bright_blue());
+    /// The run time history file, which defaults to the crate root rather than the current working directory.
+    fn history_path(&self) -> PathBuf {
+        self.history
+            .clone()
+            .unwrap_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).join("perf-history.toml"))
+    }
+}
 
-    let formatted_one_solution = res.part_one_solution.yellow();
-    let formatted_two_solution = res.part_two_solution.yellow();
+/// Prints why a file the runner uses couldn't be read or written.
+fn report_file_error(path: &Path, err: &dyn std::fmt::Display) -> RunStatus {
+    eprintln!("error: {}: {err}", path.display());
+    RunStatus::BadFile
+}
 
-    let part_one_dur = res.part_one_duration.as_micros() as f32 / 1000.0;
-    let part_two_dur = res.part_two_duration.as_micros() as f32 / 1000.0;
+/// Parses a year that has registered solutions.
+fn parse_year(text: &str) -> Result<u16, String> {
+    let year = text
+        .trim()
+        .parse::<u16>()
+        .map_err(|_| format!("`{text}` isn't a year"))?;
+    if days::get_year(year).is_none() {
+        let years: Vec<_> = days::YEARS
+            .iter()
+            .map(|year| year.year.to_string())
+            .collect();
+        return Err(format!(
+            "{year} has no solutions, expected one of {}",
+            years.join(", ")
+        ));
+    }
+    Ok(year)
+}
 
-    let formatted_one_time = format!("({part_one_dur} ms)").dimmed();
-    let formatted_two_time = format!("({part_two_dur} ms)").dimmed();
+/// Parses a day from 1 to 25.
+fn parse_day(text: &str) -> Result<u8, String> {
+    match text.trim().parse::<u8>() {
+        Ok(day @ 1..=25) => Ok(day),
+        _ => Err(format!("`{text}` isn't a day from 1 to 25")),
+    }
+}
 
-    println!("Part 1: {formatted_one_solution} {formatted_one_time}");
-    println!("Part 2: {formatted_two_solution} {formatted_two_time}\n");
+/// Parses a day like `3` or a range of days like `5-8`.
+fn parse_day_range(text: &str) -> Result<RangeInclusive<u8>, String> {
+    match text.split_once('-') {
+        Some((start, end)) => match (parse_day(start)?, parse_day(end)?) {
+            (start, end) if start > end => Err(format!("`{text}` ends before it starts")),
+            (start, end) => Ok(start..=end),
+        },
+        None => parse_day(text).map(|day| day..=day),
+    }
+}
 
-    Ok(format!("Ran day {day}"))
+/// Parses a duration like `500ms`, `10s` or `2m`. A number without a unit is in seconds.
+fn parse_duration(text: &str) -> Result<Duration, String> {
+    let (number, unit) = text
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .map_or((text, "s"), |idx| text.split_at(idx));
+    let number = number
+        .parse::<f64>()
+        .map_err(|_| format!("invalid number `{number}`"))?;
+    let secs = match unit.trim() {
+        "ms" => number / 1000.0,
+        "s" => number,
+        "m" => number * 60.0,
+        unit => return Err(format!("unknown unit `{unit}`, expected `ms`, `s` or `m`")),
+    };
+    Duration::try_from_secs_f64(secs).map_err(|err| err.to_string())
 }
 
-fn main() {
-    println!("\n{}\n", "✨ Advent of Code 2022 ✨".bold().yellow());
-    if let Some(day) = Args::parse().day {
-        run_day(day).expect("Couldn't find input");
+fn main() -> ExitCode {
+    let args = Args::parse_checked();
+    runner::install_panic_hook();
+    let answers_path = args.answers_path();
+    // A file given with `--input` isn't the day's own input, so the day's answers don't apply to it
+    let answers = if args.input.is_some() {
+        Answers::default()
     } else {
-        println!("Running all solved puzzles.\n");
-        for day in 0..=25 {
-            let _ = run_day(day);
+        match Answers::load(&answers_path) {
+            Ok(answers) => answers,
+            Err(err) => return report_file_error(&answers_path, &err).into(),
         }
+    };
+    let mut reporter = Reporter::new(args.output_format());
+
+    let single_day = if args.latest {
+        let solutions = days::get_year(args.year()).map_or(&[][..], |year| year.solutions);
+        let latest = solutions.iter().map(|solution| solution.day).max();
+        Some(latest.expect("There are no solved days"))
+    } else {
+        args.day
+    };
+    let runner = YearRunner {
+        year: args.year(),
+        inputs: args.input_source(),
+        options: RunOptions {
+            parts: args.part,
+            bench_runs: args.bench,
+            timeout: args.timeout,
+        },
+        answers: &answers,
+    };
+
+    // Watch mode starts the output over for each run
+    if let (Some(day), true) = (single_day, args.watch) {
+        runner.watch_day(day, args.output_format());
     }
-}
\ No newline at end of file
+    reporter.begin(args.year(), single_day.is_none() && args.days.is_empty());
+
+    // The results are also recorded in the history and rendered into the README
+    let (mut status, results) = if let Some(day) = single_day {
+        runner.run_day(day, &mut reporter)
+    } else {
+        let jobs = match args.jobs {
+            0 => thread::available_parallelism().map_or(1, |jobs| jobs.get()),
+            jobs => jobs,
+        };
+        let days = if args.days.is_empty() {
+            (1..=25).collect::<Vec<u8>>()
+        } else {
+            let mut days = args.days.iter().cloned().flatten().collect::<Vec<u8>>();
+            days.sort();
+            days.dedup();
+            days
+        };
+        runner.run_days(&days, jobs, &mut reporter)
+    };
+
+    reporter.finish();
+
+    // A file given with `--input` has no name to tell it apart from the day's own input in the history
+    if !results.is_empty() && args.input.is_none() {
+        let history_path = args.history_path();
+        match history::load(&history_path) {
+            Ok(history) => {
+                let entry = history::HistoryEntry::new(&results);
+                if args.compare {
+                    let regressions = history::find_regressions(&history, &entry);
+                    reporter.report_regressions(&regressions);
+                    if !regressions.is_empty() {
+                        status = status.max(RunStatus::Slower);
+                    }
+                }
+                if let Err(err) = history::append(&history_path, &entry) {
+                    status = status.max(report_file_error(&history_path, &err));
+                }
+            }
+            Err(err) => status = status.max(report_file_error(&history_path, &err)),
+        }
+    }
+
+    if args.update_readme {
+        let readme_path = readme::readme_path();
+        if let Err(err) = readme::update_readme(&readme_path, &results) {
+            status = status.max(report_file_error(&readme_path, &err));
+        }
+    }
+
+    status.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_day_range() {
+        assert_eq!(parse_day_range("3"), Ok(3..=3));
+        assert_eq!(parse_day_range("5-8"), Ok(5..=8));
+        assert!(parse_day_range("0").is_err());
+        assert!(parse_day_range("20-26").is_err());
+        assert!(parse_day_range("8-5").is_err());
+    }
+
+    #[test]
+    fn test_parse_day() {
+        assert_eq!(parse_day("25"), Ok(25));
+        assert!(parse_day("0").is_err());
+        assert!(parse_day("26").is_err());
+    }
+
+    #[test]
+    fn test_parse_year() {
+        assert_eq!(parse_year("2022"), Ok(2022));
+        assert!(parse_year("2021").is_err());
+        assert!(parse_year("year").is_err());
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("500ms"), Ok(Duration::from_millis(500)));
+        assert_eq!(parse_duration("1.5s"), Ok(Duration::from_millis(1500)));
+        assert_eq!(parse_duration("2m"), Ok(Duration::from_secs(120)));
+        assert_eq!(parse_duration("3"), Ok(Duration::from_secs(3)));
+        assert!(parse_duration("3h").is_err());
+        assert!(parse_duration("ms").is_err());
+    }
+}
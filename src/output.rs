@@ -0,0 +1,742 @@
+use std::{path::Path, time::Duration};
+
+use clap::ValueEnum;
+use colored::Colorize;
+
+use crate::{
+    answer::Answer,
+    error::SolveError,
+    history::Regression,
+    runner::{DayResult, Failure, PartResult, PartStatus, Timing},
+};
+
+/// The format the runner prints its results in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Colored text meant for humans
+    #[default]
+    Text,
+    /// A JSON array with one object per day and part
+    Json,
+    /// CSV with one row per day and part
+    Csv,
+    /// Only the answer of each part, one per line
+    Answers,
+}
+
+const CSV_HEADER: &str =
+    "year,day,title,input,part,answer,expected,parse_duration_ns,duration_ns,allocations,allocated_bytes,peak_bytes,status,error";
+
+/// Prints the results of the runner in a given [`OutputFormat`].
+///
+/// Machine-readable formats only write records to stdout. Anything else, like warnings, goes to stderr.
+pub struct Reporter {
+    format: OutputFormat,
+    records_written: usize,
+    /// A row for each reported day, shown in the summary table at the end of the run.
+    summary: Vec<SummaryRow>,
+}
+
+/// A day's row in the summary table.
+struct SummaryRow {
+    day: u8,
+    title: String,
+    /// The answer and run time of each part, or [`None`] if the part wasn't run.
+    parts: [Option<(String, Duration)>; 2],
+    status: Option<PartStatus>,
+}
+
+impl SummaryRow {
+    fn new(res: &DayResult) -> Self {
+        let part = |part_res: &PartResult| {
+            let answer = match &part_res.solution {
+                // A block doesn't fit in a table cell
+                Answer::Block(_) => format!("[{} lines]", part_res.solution.lines().len()),
+                answer => answer.to_string(),
+            };
+            (!part_res.skipped).then_some((answer, part_res.timing.duration))
+        };
+        Self {
+            day: res.day,
+            title: res.title_with_input(),
+            parts: [part(&res.part_one), part(&res.part_two)],
+            status: Some(res.status()),
+        }
+    }
+
+    fn unregistered(day: u8) -> Self {
+        Self {
+            day,
+            title: String::new(),
+            parts: [None, None],
+            status: None,
+        }
+    }
+}
+
+impl Reporter {
+    pub fn new(format: OutputFormat) -> Self {
+        Self {
+            format,
+            records_written: 0,
+            summary: Vec::new(),
+        }
+    }
+
+    /// Prints whatever comes before the results of a given year, like the banner or a CSV header.
+    pub fn begin(&mut self, year: u16, running_all: bool) {
+        match self.format {
+            OutputFormat::Text => {
+                let banner = format!("✨ Advent of Code {year} ✨");
+                println!("\n{}\n", banner.bold().yellow());
+                if running_all {
+                    println!("Running all solved puzzles.\n");
+                }
+            }
+            OutputFormat::Json => print!("["),
+            OutputFormat::Csv => println!("{CSV_HEADER}"),
+            OutputFormat::Answers => {}
+        }
+    }
+
+    pub fn report_day(&mut self, res: &DayResult) {
+        match self.format {
+            OutputFormat::Text => {
+                print_day_text(res);
+                self.summary.push(SummaryRow::new(res));
+            }
+            OutputFormat::Json => {
+                for (part, part_res) in res.parts() {
+                    // Records are separated by a comma at the end of the previous record's line.
+                    let separator = if self.records_written == 0 { "" } else { "," };
+                    print!("{separator}\n{}", json_record(res, part, part_res));
+                    self.records_written += 1;
+                }
+            }
+            OutputFormat::Csv => {
+                for (part, part_res) in res.parts() {
+                    println!("{}", csv_record(res, part, part_res));
+                    self.records_written += 1;
+                }
+            }
+            OutputFormat::Answers => {
+                // A part without an answer still gets a line, so that the answers can be told apart by line
+                for (part, part_res) in res.parts() {
+                    println!("{}", line_answer(&part_res.solution));
+                    if part_res.failure.is_some() {
+                        let message =
+                            failure_message(part_res).unwrap_or_else(|| "timed out".to_string());
+                        eprintln!("Day {} part {part}: {message}", res.day);
+                    }
+                    self.records_written += 1;
+                }
+            }
+        }
+    }
+
+    /// Prints a heading above the next results in text output, e.g. to tell apart runs on different inputs.
+    pub fn report_heading(&mut self, heading: &str) {
+        if self.format == OutputFormat::Text {
+            println!("{}", heading.bold().underline());
+        }
+    }
+
+    /// Warns that a day's solution panicked or was incorrect for some of its inputs.
+    pub fn report_failing_inputs(&mut self, day: u8, inputs: &[&str]) {
+        let message = format!("Day {day} failed on inputs: {}", inputs.join(", "));
+        match self.format {
+            OutputFormat::Text => println!("{}\n", message.red().bold()),
+            _ => eprintln!("{message}"),
+        }
+    }
+
+    /// Reports the parts that got slower than in the previous runs.
+    pub fn report_regressions(&mut self, regressions: &[Regression]) {
+        let lines = regressions
+            .iter()
+            .map(|regression| {
+                let input = regression
+                    .input
+                    .as_ref()
+                    .map_or(String::new(), |input| format!(" ({input})"));
+                let (current, baseline) = (regression.current.mean, regression.baseline.mean);
+                let change = current.as_secs_f64() / baseline.as_secs_f64().max(f64::EPSILON) - 1.0;
+                format!(
+                    "{} day {} part {}{input}: {} -> {} (+{:.1}%)",
+                    regression.year,
+                    regression.day,
+                    regression.part,
+                    format_duration(baseline),
+                    format_duration(current),
+                    change * 100.0
+                )
+            })
+            .collect::<Vec<_>>();
+
+        match self.format {
+            OutputFormat::Text if lines.is_empty() => {
+                println!(
+                    "{}\n",
+                    "No parts got slower than in the previous runs.".green()
+                );
+            }
+            OutputFormat::Text => {
+                println!(
+                    "{}",
+                    "Parts that got slower than in the previous runs:"
+                        .red()
+                        .bold()
+                );
+                for line in lines {
+                    println!("  {}", line.red());
+                }
+                println!();
+            }
+            _ => {
+                for line in lines {
+                    eprintln!("Slower: {line}");
+                }
+            }
+        }
+    }
+
+    /// Reports a day that couldn't be run because its input couldn't be read.
+    pub fn report_missing_input(&mut self, day: u8, error: &str) {
+        let message = format!("Couldn't read the input: {error}");
+        match self.format {
+            OutputFormat::Text => {
+                println!("{}", format!("Day {day}").bold().bright_blue());
+                println!("{}\n", message.red());
+            }
+            _ => eprintln!("Day {day}: {message}"),
+        }
+    }
+
+    /// Reports a day that has an input but no registered solution.
+    pub fn report_unregistered(&mut self, day: u8) {
+        let message = "No solution registered for this day";
+        match self.format {
+            OutputFormat::Text => {
+                println!("{}", format!("Day {day}").bold().bright_blue());
+                println!("{}\n", message.yellow());
+                self.summary.push(SummaryRow::unregistered(day));
+            }
+            _ => eprintln!("Day {day}: {message}"),
+        }
+    }
+
+    /// Reports a summary table of every day and how long running all of the days took in total.
+    ///
+    /// `wall_time` is the real time elapsed, while `solving_time` is the sum of every part's run time.
+    pub fn report_totals(&mut self, wall_time: Duration, solving_time: Duration) {
+        if self.format == OutputFormat::Text {
+            if !self.summary.is_empty() {
+                print_summary(&self.summary);
+            }
+
+            let totals = format!(
+                "Total: {} wall clock, {} solving",
+                format_duration(wall_time),
+                format_duration(solving_time)
+            );
+            println!("{}\n", totals.bold());
+        }
+    }
+
+    /// Prints whatever comes after the results, like the end of a JSON array.
+    pub fn finish(&mut self) {
+        if self.format == OutputFormat::Json {
+            println!("\n]");
+        }
+    }
+}
+
+fn print_day_text(res: &DayResult) {
+    println!(
+        "{}",
+        format!("Day {}: {}", res.day, res.title_with_input())
+            .bold()
+            .bright_blue()
+    );
+
+    if let Some(Failure::Error(err)) = &res.parse_failure {
+        let mut lines = format_diagnostic(err, res.input_path.as_deref()).into_iter();
+        if let Some(heading) = lines.next() {
+            println!("{}", heading.red().bold());
+        }
+        for line in lines {
+            println!("{}", line.bright_blue());
+        }
+    } else if let Some(failure) = &res.parse_failure {
+        println!("{} {}", "Parse:".dimmed(), format_failure(failure));
+    } else if res.parse.stats.is_some() {
+        println!("{}", "Parse".dimmed());
+        println!("  {}", format_timing(&res.parse).dimmed());
+    } else {
+        println!(
+            "{}",
+            format!("Parse ({})", format_timing(&res.parse)).dimmed()
+        );
+    }
+
+    for (part, part_res) in res.parts() {
+        // Blocks are printed on their own lines below the part
+        let solution = match (part_res.status(), &part_res.solution) {
+            (PartStatus::Timeout, _) => "TIMEOUT".red().bold(),
+            (PartStatus::Error, _) => "ERROR".red().bold(),
+            (PartStatus::Panic, _) => "PANIC".red().bold(),
+            (_, Answer::Block(_)) => "".normal(),
+            (_, solution) => solution.to_string().yellow(),
+        };
+        let verdict = match (part_res.is_correct(), &part_res.expected) {
+            (Some(true), _) => format!(" {}", "✓".green()),
+            (Some(false), Some(Answer::Block(_))) => {
+                format!(" {} {}", "✗".red(), "expected a different block".red())
+            }
+            (Some(false), Some(expected)) => {
+                format!(" {} {}", "✗".red(), format!("expected {expected}").red())
+            }
+            _ => String::new(),
+        };
+
+        if part_res.timing.stats.is_some() {
+            println!("Part {part}: {solution}{verdict}");
+            println!("  {}", format_timing(&part_res.timing).dimmed());
+        } else {
+            let time = format!("({})", format_timing(&part_res.timing)).dimmed();
+            println!("Part {part}: {solution}{verdict} {time}");
+        }
+
+        if let (Answer::Block(_), None) = (&part_res.solution, &part_res.failure) {
+            for line in part_res.solution.lines() {
+                println!("  {}", line.yellow());
+            }
+            if let (Some(false), Some(expected @ Answer::Block(_))) =
+                (part_res.is_correct(), &part_res.expected)
+            {
+                println!("  {}", "Expected:".red());
+                for line in expected.lines() {
+                    println!("  {}", line.red());
+                }
+            }
+        }
+
+        // Failures while parsing are already shown on the parse line
+        if let (Some(Failure::Panic(message)), None) = (&part_res.failure, &res.parse_failure) {
+            println!("  {}", message.red());
+        }
+    }
+
+    println!();
+}
+
+/// Prints an aligned table with the answers, run times and status of every day.
+/// The run time of the slowest part is highlighted.
+fn print_summary(rows: &[SummaryRow]) {
+    let slowest = slowest_part(rows);
+    let cells = rows
+        .iter()
+        .map(|row| {
+            let mut cells = vec![row.day.to_string(), row.title.clone()];
+            for part in &row.parts {
+                match part {
+                    Some((answer, duration)) => {
+                        cells.extend([answer.clone(), format_duration(*duration)])
+                    }
+                    None => cells.extend(["-".to_string(), "-".to_string()]),
+                }
+            }
+            cells.push(
+                row.status
+                    .map_or("unregistered", |status| status.as_str())
+                    .to_string(),
+            );
+            cells
+        })
+        .collect::<Vec<_>>();
+
+    let header = ["Day", "Title", "Part 1", "Time", "Part 2", "Time", "Status"];
+    let widths = header
+        .iter()
+        .enumerate()
+        .map(|(col, title)| {
+            cells
+                .iter()
+                .map(|row| row[col].chars().count())
+                .chain([title.len()])
+                .max()
+                .unwrap_or_default()
+        })
+        .collect::<Vec<_>>();
+
+    let header = header
+        .iter()
+        .zip(&widths)
+        .map(|(title, width)| format!("{title:<width$}"))
+        .collect::<Vec<_>>()
+        .join("  ");
+    println!("{}", header.trim_end().bold());
+
+    for (row_idx, (row, cells)) in rows.iter().zip(&cells).enumerate() {
+        let line = cells
+            .iter()
+            .zip(&widths)
+            .enumerate()
+            .map(|(col, (cell, width))| {
+                let padded = format!("{cell:<width$}");
+                match col {
+                    // Answers
+                    2 | 4 => padded.yellow(),
+                    // Run times
+                    3 | 5 if slowest == Some((row_idx, col / 2 - 1)) => padded.red().bold(),
+                    3 | 5 => padded.dimmed(),
+                    _ if col == cells.len() - 1 => match row.status {
+                        Some(PartStatus::Solved) => padded.green(),
+                        Some(PartStatus::Unsolved) | None => padded.yellow(),
+                        Some(_) => padded.red().bold(),
+                    },
+                    _ => padded.normal(),
+                }
+                .to_string()
+            })
+            .collect::<Vec<_>>()
+            .join("  ");
+        println!("{}", line.trim_end());
+    }
+
+    if let Some((row_idx, part_idx)) = slowest {
+        let row = &rows[row_idx];
+        let (_, duration) = row.parts[part_idx].as_ref().unwrap();
+        println!(
+            "\nSlowest: Day {} part {} ({})",
+            row.day,
+            part_idx + 1,
+            format_duration(*duration).red().bold()
+        );
+    }
+    println!();
+}
+
+/// Finds the row and part index of the part that took the longest to run.
+fn slowest_part(rows: &[SummaryRow]) -> Option<(usize, usize)> {
+    rows.iter()
+        .enumerate()
+        .flat_map(|(row_idx, row)| {
+            row.parts
+                .iter()
+                .enumerate()
+                .filter_map(move |(part_idx, part)| {
+                    part.as_ref()
+                        .map(|(_, duration)| ((row_idx, part_idx), *duration))
+                })
+        })
+        .max_by_key(|(_, duration)| *duration)
+        .map(|(idx, _)| idx)
+}
+
+/// Formats a failure of the parse step, e.g. "TIMEOUT" or "PANIC" with the panic message.
+fn format_failure(failure: &Failure) -> String {
+    match failure {
+        Failure::Timeout => "TIMEOUT".red().bold().to_string(),
+        Failure::Error(err) => format!("{} {}", "ERROR".red().bold(), err.to_string().red()),
+        Failure::Panic(message) => format!("{} {}", "PANIC".red().bold(), message.red()),
+    }
+}
+
+/// Formats a parse error like a compiler error, pointing to the offending text in the input file:
+///
+/// ```text
+/// error: expected a number
+///  --> src/days/day04/input.txt:2:3
+///   |
+/// 2 | 2-x,4-5
+///   |   ^
+/// ```
+///
+/// An error that couldn't be located in the input only gets the first line.
+fn format_diagnostic(err: &SolveError, path: Option<&Path>) -> Vec<String> {
+    let Some(location) = &err.location else {
+        return vec![format!("error: {err}")];
+    };
+
+    let path = match path {
+        Some(path) if path == Path::new("-") => "<stdin>".to_string(),
+        Some(path) => path.display().to_string(),
+        None => "<input>".to_string(),
+    };
+    let line_number = location.line.to_string();
+    let gutter = " ".repeat(line_number.len());
+    // Underline the offending text up to the end of its line, or the spot where missing text should be
+    let source_line = location.source_line.trim_end_matches('\r');
+    let underline = err
+        .text
+        .lines()
+        .next()
+        .map_or(0, |text| text.chars().count())
+        .min(
+            source_line
+                .chars()
+                .count()
+                .saturating_sub(location.column - 1),
+        )
+        .max(1);
+
+    vec![
+        format!("error: {}", err.message),
+        format!("{gutter}--> {path}:{}:{}", location.line, location.column),
+        format!("{gutter} |"),
+        format!("{line_number} | {source_line}"),
+        format!(
+            "{gutter} | {}{}",
+            " ".repeat(location.column - 1),
+            "^".repeat(underline)
+        ),
+    ]
+}
+
+/// Formats a step's run time, or its run time statistics if it was benchmarked.
+fn format_timing(timing: &Timing) -> String {
+    let time = match &timing.stats {
+        Some(stats) => format!(
+            "min {}, median {}, mean {}, std dev {}, {} outliers ({} runs)",
+            format_duration(stats.min),
+            format_duration(stats.median),
+            format_duration(stats.mean),
+            format_duration(stats.std_dev),
+            stats.outliers,
+            stats.runs,
+        ),
+        None => format_duration(timing.duration),
+    };
+
+    match &timing.allocs {
+        Some(allocs) => format!(
+            "{time}, {} allocs, {} allocated, {} peak",
+            allocs.allocations,
+            format_bytes(allocs.bytes),
+            format_bytes(allocs.peak_bytes),
+        ),
+        None => time,
+    }
+}
+
+/// Formats a number of bytes with a binary unit, e.g. "1.5 KiB".
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{bytes} B")
+    } else {
+        format!("{size:.1} {}", UNITS[unit])
+    }
+}
+
+/// Formats a duration as milliseconds, e.g. "0.123 ms".
+pub fn format_duration(duration: Duration) -> String {
+    format!("{} ms", duration.as_micros() as f32 / 1000.0)
+}
+
+fn json_record(res: &DayResult, part: u8, part_res: &PartResult) -> String {
+    let answer = json_answer(&part_res.solution);
+    let expected = part_res
+        .expected
+        .as_ref()
+        .map_or("null".to_string(), json_answer);
+    let error =
+        failure_message(part_res).map_or("null".to_string(), |message| json_string(&message));
+    let [allocations, allocated_bytes, peak_bytes] =
+        alloc_fields(part_res).map(|field| field.map_or("null".to_string(), |n| n.to_string()));
+    format!(
+        r#"  {{"year": {}, "day": {}, "title": {}, "input": {}, "part": {part}, "answer": {answer}, "expected": {expected}, "parse_duration_ns": {}, "duration_ns": {}, "allocations": {allocations}, "allocated_bytes": {allocated_bytes}, "peak_bytes": {peak_bytes}, "status": "{}", "error": {error}}}"#,
+        res.year,
+        res.day,
+        json_string(res.title),
+        res.input.as_deref().map_or("null".to_string(), json_string),
+        res.parse.duration.as_nanos(),
+        part_res.timing.duration.as_nanos(),
+        part_res.status().as_str(),
+    )
+}
+
+fn csv_record(res: &DayResult, part: u8, part_res: &PartResult) -> String {
+    let [allocations, allocated_bytes, peak_bytes] =
+        alloc_fields(part_res).map(|field| field.map_or(String::new(), |n| n.to_string()));
+    format!(
+        "{},{},{},{},{part},{},{},{},{},{allocations},{allocated_bytes},{peak_bytes},{},{}",
+        res.year,
+        res.day,
+        csv_field(res.title),
+        csv_field(res.input.as_deref().unwrap_or_default()),
+        csv_field(&csv_answer(&part_res.solution)),
+        csv_field(
+            &part_res
+                .expected
+                .as_ref()
+                .map(csv_answer)
+                .unwrap_or_default()
+        ),
+        res.parse.duration.as_nanos(),
+        part_res.timing.duration.as_nanos(),
+        part_res.status().as_str(),
+        csv_field(&failure_message(part_res).unwrap_or_default()),
+    )
+}
+
+/// Gets the allocation count, allocated bytes and peak bytes of a part, if allocations were counted.
+fn alloc_fields(part_res: &PartResult) -> [Option<u64>; 3] {
+    match &part_res.timing.allocs {
+        Some(allocs) => [allocs.allocations, allocs.bytes, allocs.peak_bytes].map(Some),
+        None => [None; 3],
+    }
+}
+
+/// Gets the panic message of a part that panicked, or the error if the input couldn't be parsed.
+fn failure_message(part_res: &PartResult) -> Option<String> {
+    match &part_res.failure {
+        Some(Failure::Error(err)) => Some(err.to_string()),
+        Some(Failure::Panic(message)) => Some(message.clone()),
+        _ => None,
+    }
+}
+
+/// Converts an answer to JSON. Integers are numbers, blocks are arrays of lines and unsolved answers are `null`.
+fn json_answer(answer: &Answer) -> String {
+    match answer {
+        Answer::Integer(number) => number.to_string(),
+        Answer::Text(text) => json_string(text),
+        Answer::Block(_) => {
+            let lines = answer.lines();
+            let lines = lines
+                .iter()
+                .map(|line| json_string(line))
+                .collect::<Vec<_>>();
+            format!("[{}]", lines.join(", "))
+        }
+        Answer::Unsolved => "null".to_string(),
+    }
+}
+
+/// Converts an answer to a CSV field's text, leaving it empty if it's unsolved.
+fn csv_answer(answer: &Answer) -> String {
+    match answer {
+        Answer::Unsolved => String::new(),
+        answer => answer.to_string(),
+    }
+}
+
+/// Converts an answer to a single line, joining the lines of a block with `\n`, so that each answer takes one line.
+fn line_answer(answer: &Answer) -> String {
+    match answer {
+        Answer::Block(_) => answer.lines().join("\\n"),
+        answer => answer.to_string(),
+    }
+}
+
+/// Quotes and escapes a string as a JSON string literal.
+fn json_string(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len() + 2);
+    escaped.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+/// Quotes a CSV field if it contains characters that need to be escaped.
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_string() {
+        assert_eq!(json_string("CMZ"), r#""CMZ""#);
+        assert_eq!(json_string("a \"b\"\n\\"), r#""a \"b\"\n\\""#);
+    }
+
+    #[test]
+    fn test_json_answer() {
+        assert_eq!(json_answer(&Answer::Integer(24000)), "24000");
+        assert_eq!(json_answer(&Answer::from("CMZ")), r#""CMZ""#);
+        assert_eq!(json_answer(&Answer::from("#.\n.#")), r##"["#.", ".#"]"##);
+        assert_eq!(json_answer(&Answer::Unsolved), "null");
+    }
+
+    #[test]
+    fn test_line_answer() {
+        assert_eq!(line_answer(&Answer::Integer(24000)), "24000");
+        assert_eq!(line_answer(&Answer::from("#.\n.#")), r"#.\n.#");
+        assert_eq!(line_answer(&Answer::Unsolved), "-");
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+
+    #[test]
+    fn test_slowest_part() {
+        let row = |day, millis: [u64; 2]| SummaryRow {
+            day,
+            title: String::new(),
+            parts: millis.map(|millis| Some(("-".to_string(), Duration::from_millis(millis)))),
+            status: Some(PartStatus::Solved),
+        };
+        let rows = [row(1, [3, 5]), SummaryRow::unregistered(2), row(3, [8, 2])];
+        assert_eq!(slowest_part(&rows), Some((2, 0)));
+        assert_eq!(slowest_part(&[SummaryRow::unregistered(4)]), None);
+    }
+
+    #[test]
+    fn test_format_diagnostic() {
+        let input = "2-4,6-8\n2-x,4-5";
+        let err = SolveError::new("expected a number", &input[10..11]).locate(input);
+        assert_eq!(
+            format_diagnostic(&err, Some(Path::new("day04/input.txt"))),
+            [
+                "error: expected a number",
+                " --> day04/input.txt:2:3",
+                "  |",
+                "2 | 2-x,4-5",
+                "  |   ^",
+            ]
+        );
+
+        let err = SolveError::new("expected a number", "x");
+        assert_eq!(
+            format_diagnostic(&err, None),
+            ["error: expected a number: `x`"]
+        );
+    }
+
+    #[test]
+    fn test_csv_field() {
+        assert_eq!(csv_field("Camp Cleanup"), "Camp Cleanup");
+        assert_eq!(csv_field("a, \"b\""), r#""a, ""b""""#);
+    }
+}
@@ -0,0 +1,182 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    output::format_duration,
+    runner::{DayResult, PartStatus},
+};
+
+/// The comment that marks the start of the results table in the README.
+pub const START_MARKER: &str = "<!-- results:start -->";
+/// The comment that marks the end of the results table in the README.
+pub const END_MARKER: &str = "<!-- results:end -->";
+
+/// The header of the results table.
+const HEADER: &str = "| Year | Day | Title | Part 1 | Part 2 | Parse | Part 1 time | Part 2 time |\n\
+                      | ---: | --: | ----- | :----: | :----: | ----: | ----------: | ----------: |\n";
+
+/// Renders the table row with the status and run times of a day. The answers themselves are hidden.
+fn render_row(res: &DayResult) -> String {
+    let status = |status: PartStatus| match status {
+        PartStatus::Solved => "⭐",
+        PartStatus::Unsolved => "-",
+        PartStatus::Incorrect => "❌ incorrect",
+        PartStatus::Timeout => "⏱️ timeout",
+        PartStatus::Error => "⚠️ error",
+        PartStatus::Panic => "💥 panic",
+    };
+    let time = |status: PartStatus, duration| match status {
+        PartStatus::Solved | PartStatus::Incorrect => format_duration(duration),
+        _ => "-".to_string(),
+    };
+    let (one, two) = (res.part_one.status(), res.part_two.status());
+    format!(
+        "| {} | {} | {} | {} | {} | {} | {} | {} |\n",
+        res.year,
+        res.day,
+        res.title_with_input().replace('|', "\\|"),
+        status(one),
+        status(two),
+        format_duration(res.parse.duration),
+        time(one, res.part_one.timing.duration),
+        time(two, res.part_two.timing.duration),
+    )
+}
+
+/// Renders a Markdown table with the status and run times of each day. The answers themselves are hidden.
+pub fn render_table(results: &[DayResult]) -> String {
+    let mut table = String::from(HEADER);
+    for res in results {
+        table.push_str(&render_row(res));
+    }
+    table
+}
+
+/// Reads the year and day from the first two cells of a table row.
+fn row_key(row: &str) -> Option<(u16, u8)> {
+    let mut cells = row.strip_prefix('|')?.split('|').map(str::trim);
+    Some((cells.next()?.parse().ok()?, cells.next()?.parse().ok()?))
+}
+
+/// Merges the rows of the results into an existing table. The rows of the days that were run are replaced, while
+/// those of the other days are kept, so that running some of the days doesn't remove the rest from the table.
+fn merge_table(existing: &str, results: &[DayResult]) -> String {
+    let mut rows: Vec<((u16, u8), String)> = existing
+        .lines()
+        .filter_map(|row| Some((row_key(row)?, format!("{row}\n"))))
+        .filter(|(key, _)| !results.iter().any(|res| (res.year, res.day) == *key))
+        .collect();
+    rows.extend(
+        results
+            .iter()
+            .map(|res| ((res.year, res.day), render_row(res))),
+    );
+    // The sort is stable, so several inputs of the same day stay in the order they were run in
+    rows.sort_by_key(|(key, _)| *key);
+    let mut table = String::from(HEADER);
+    for (_, row) in rows {
+        table.push_str(&row);
+    }
+    table
+}
+
+/// Finds the byte offset of a marker that is on a line of its own, so that markers mentioned in the text are ignored.
+fn find_marker(text: &str, marker: &str) -> Option<usize> {
+    let mut offset = 0;
+    for line in text.split_inclusive('\n') {
+        if line.trim_end() == marker {
+            return Some(offset);
+        }
+        offset += line.len();
+    }
+    None
+}
+
+/// Replaces the text between the start and end markers with the table of the results, merged into the table that
+/// was there before.
+///
+/// Returns [`None`] if the markers are missing or in the wrong order.
+fn replace_table(text: &str, results: &[DayResult]) -> Option<String> {
+    let start = find_marker(text, START_MARKER)? + START_MARKER.len();
+    let end = start + find_marker(&text[start..], END_MARKER)?;
+    let table = merge_table(&text[start..end], results);
+    Some(format!("{}\n{table}{}", &text[..start], &text[end..]))
+}
+
+/// The README of the crate, independent of the directory the binary is run from.
+pub fn readme_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("README.md")
+}
+
+/// Rewrites the results table between the markers in a README file with the rows of the results, keeping the rows of
+/// the days that weren't run.
+pub fn update_readme(path: &Path, results: &[DayResult]) -> io::Result<()> {
+    let text = fs::read_to_string(path)?;
+    let updated = replace_table(&text, results).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "{} has no `{START_MARKER}` and `{END_MARKER}` markers",
+                path.display()
+            ),
+        )
+    })?;
+    fs::write(path, updated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answer::Answer;
+
+    fn result(day: u8, input: Option<&str>) -> DayResult {
+        DayResult {
+            year: 2022,
+            day,
+            title: "Title",
+            input: input.map(String::from),
+            input_path: None,
+            parse: Default::default(),
+            parse_failure: None,
+            part_one: Default::default(),
+            part_two: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_replace_table() {
+        let text = format!("# Title\n{START_MARKER}\nold table\n{END_MARKER}\nMore text\n");
+        assert_eq!(
+            replace_table(&text, &[result(1, None)]).unwrap(),
+            format!(
+                "# Title\n{START_MARKER}\n{}{END_MARKER}\nMore text\n",
+                render_table(&[result(1, None)])
+            )
+        );
+        assert_eq!(replace_table("no markers", &[]), None);
+        assert_eq!(
+            replace_table(&format!("See `{START_MARKER}`\n{END_MARKER}"), &[]),
+            None
+        );
+        assert_eq!(
+            replace_table(&format!("{END_MARKER}\n{START_MARKER}"), &[]),
+            None
+        );
+    }
+
+    #[test]
+    fn test_merge_table() {
+        let existing = render_table(&[result(1, None), result(3, None), result(3, Some("big"))]);
+        let mut rerun = result(3, None);
+        rerun.part_one.solution = Answer::Integer(1);
+        let merged = merge_table(&existing, &[rerun, result(2, None)]);
+        let rows: Vec<_> = merged.lines().skip(2).collect();
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[0], render_row(&result(1, None)).trim_end());
+        assert_eq!(rows[1], render_row(&result(2, None)).trim_end());
+        assert!(rows[2].starts_with("| 2022 | 3 | Title | ⭐ |"));
+        assert!(merged.starts_with(HEADER));
+    }
+}
@@ -0,0 +1,997 @@
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    fs, io,
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    process::ExitCode,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc::{self, RecvTimeoutError, Sender},
+    },
+    thread,
+    time::{Duration, Instant, SystemTime},
+};
+
+use clap::ValueEnum;
+use colored::Colorize;
+
+use crate::{
+    alloc::{self, AllocStats},
+    answer::Answer,
+    answers::Answers,
+    bench::{self, BenchStats},
+    days,
+    error::SolveError,
+    inputs::{read_input_file, Input, InputDir},
+    output::{OutputFormat, Reporter},
+    solution::{RegisteredSolution, Step, StepRunner},
+};
+
+/// The status of a part after it has been run.
+///
+/// The statuses are ordered from best to worst, so the worst status of several parts is their maximum.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PartStatus {
+    /// The part returned a solution.
+    Solved,
+    /// The part returned [`None`], i.e. it hasn't been solved yet.
+    Unsolved,
+    /// The part returned a solution that doesn't match the expected answer, or no solution even though there is one.
+    Incorrect,
+    /// The part didn't finish within the time limit.
+    Timeout,
+    /// The input couldn't be parsed.
+    Error,
+    /// The part panicked.
+    Panic,
+}
+
+impl PartStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Solved => "solved",
+            Self::Unsolved => "unsolved",
+            Self::Incorrect => "incorrect",
+            Self::Timeout => "timeout",
+            Self::Error => "error",
+            Self::Panic => "panic",
+        }
+    }
+}
+
+/// How long a step of a solution took to run and how much it allocated.
+#[derive(Clone, Debug, Default)]
+pub struct Timing {
+    /// The run time of the step. When benchmarking, this is the median run time.
+    pub duration: Duration,
+    /// Run time statistics, if the step was benchmarked.
+    pub stats: Option<BenchStats>,
+    /// Allocations made by the step, if the `count-allocs` feature is enabled.
+    /// When benchmarking, these are from the last run.
+    pub allocs: Option<AllocStats>,
+}
+
+/// Why a step of a solution failed to finish.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Failure {
+    /// The step didn't finish within the time limit.
+    Timeout,
+    /// The input couldn't be parsed.
+    Error(Box<SolveError>),
+    /// The step panicked with a given message and location.
+    Panic(String),
+}
+
+/// The result of running one part of a puzzle.
+#[derive(Default)]
+pub struct PartResult {
+    pub solution: Answer,
+    pub timing: Timing,
+    /// The expected answer, if one has been recorded.
+    pub expected: Option<Answer>,
+    /// Why the part, or the parsing before it, failed to finish.
+    pub failure: Option<Failure>,
+    /// Whether the part wasn't selected to be run.
+    pub skipped: bool,
+}
+
+impl PartResult {
+    pub fn status(&self) -> PartStatus {
+        match self.is_correct() {
+            _ if self.failure == Some(Failure::Timeout) => PartStatus::Timeout,
+            _ if matches!(self.failure, Some(Failure::Error(_))) => PartStatus::Error,
+            _ if self.failure.is_some() => PartStatus::Panic,
+            // A part that stopped returning an answer that has been recorded is as broken as a wrong answer
+            Some(false) => PartStatus::Incorrect,
+            _ if !self.solution.is_solved() => PartStatus::Unsolved,
+            _ => PartStatus::Solved,
+        }
+    }
+
+    /// Checks if the solution matches the expected answer.
+    ///
+    /// Returns [`None`] if there is no expected answer to compare against.
+    pub fn is_correct(&self) -> Option<bool> {
+        let expected = self.expected.as_ref()?;
+        Some(self.solution == *expected)
+    }
+}
+
+/// The results of parsing the input and running both parts of a day's puzzle.
+pub struct DayResult {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    /// The name of the input from the day's `inputs` directory, or [`None`] for its main input.
+    pub input: Option<String>,
+    /// The file the input was read from, which parse errors point into. `-` for stdin.
+    pub input_path: Option<PathBuf>,
+    pub parse: Timing,
+    /// Why parsing the input failed to finish.
+    pub parse_failure: Option<Failure>,
+    pub part_one: PartResult,
+    pub part_two: PartResult,
+}
+
+/// Which parts of each day to run.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum PartSelection {
+    /// Only part one
+    #[value(name = "1")]
+    One,
+    /// Only part two
+    #[value(name = "2")]
+    Two,
+    /// Both parts
+    #[default]
+    Both,
+}
+
+impl PartSelection {
+    /// Checks if a given step should be run. The input is always parsed.
+    pub fn includes(&self, step: Step) -> bool {
+        !matches!(
+            (self, step),
+            (Self::One, Step::PartTwo) | (Self::Two, Step::PartOne)
+        )
+    }
+}
+
+/// Options for how the runner runs each day's solution.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RunOptions {
+    /// The parts to run. The other parts are skipped and left out of the results.
+    pub parts: PartSelection,
+    /// Run each step a given number of times and collect run time statistics.
+    pub bench_runs: Option<u32>,
+    /// The time limit for each step. Steps that exceed it are left running in the background and marked as timed out.
+    pub timeout: Option<Duration>,
+}
+
+impl DayResult {
+    /// Runs a solution's steps and records their solutions and timings.
+    pub fn from_solution(
+        input: &str,
+        solution: &'static RegisteredSolution,
+        options: RunOptions,
+    ) -> Self {
+        let mut res = Self {
+            year: solution.year,
+            day: solution.day,
+            title: solution.title,
+            input: None,
+            input_path: None,
+            parse: Timing::default(),
+            parse_failure: None,
+            part_one: PartResult {
+                skipped: !options.parts.includes(Step::PartOne),
+                ..Default::default()
+            },
+            part_two: PartResult {
+                skipped: !options.parts.includes(Step::PartTwo),
+                ..Default::default()
+            },
+        };
+
+        if let Some(timeout) = options.timeout {
+            res.run_with_timeout(input, solution, options, timeout);
+        } else {
+            (solution.run)(
+                input,
+                &mut TimingRunner {
+                    res: &mut res,
+                    options,
+                },
+            );
+        }
+
+        res
+    }
+
+    /// Runs a solution on worker threads, giving up on steps that exceed the timeout.
+    ///
+    /// A thread can't be stopped from the outside, so a timed out step is left running on its own thread
+    /// and the remaining steps are run on a new thread, which has to parse the input again.
+    fn run_with_timeout(
+        &mut self,
+        input: &str,
+        solution: &'static RegisteredSolution,
+        options: RunOptions,
+        timeout: Duration,
+    ) {
+        let mut skip = [Step::PartOne, Step::PartTwo]
+            .into_iter()
+            .filter(|&step| !options.parts.includes(step))
+            .collect::<Vec<Step>>();
+        let mut parsed = false;
+
+        loop {
+            let (sender, receiver) = mpsc::channel();
+            let mut runner = ChannelRunner {
+                sender,
+                bench_runs: options.bench_runs,
+                skip: skip.clone(),
+            };
+            let input = input.to_string();
+            thread::spawn(move || (solution.run)(&input, &mut runner));
+
+            let mut running = None;
+            loop {
+                match receiver.recv_timeout(timeout) {
+                    Ok(StepEvent::Started(step)) => running = Some(step),
+                    Ok(StepEvent::Finished(step, outcome, timing)) => {
+                        running = None;
+                        // Only the first parse is recorded, the others are just for rerunning the parts.
+                        if step != Step::Parse || !parsed {
+                            self.record(step, outcome, timing);
+                        }
+                        if step == Step::Parse {
+                            parsed = true;
+                        } else {
+                            skip.push(step);
+                        }
+                    }
+                    Err(RecvTimeoutError::Timeout) if running.is_some() => break,
+                    Err(RecvTimeoutError::Timeout) => {}
+                    Err(RecvTimeoutError::Disconnected) => return,
+                }
+            }
+
+            let step = running.unwrap_or(Step::Parse);
+            if step == Step::Parse && parsed {
+                // Only parsing the input again for the remaining parts timed out, so the first parse still counts
+                // and the parts that haven't run yet can't be run at all.
+                for step in [Step::PartOne, Step::PartTwo] {
+                    if !skip.contains(&step) {
+                        self.part_mut(step).failure = Some(Failure::Timeout);
+                    }
+                }
+                return;
+            }
+            let timing = Timing {
+                duration: timeout,
+                ..Default::default()
+            };
+            self.record(step, Err(Failure::Timeout), timing);
+            if step == Step::Parse {
+                return;
+            }
+            skip.push(step);
+
+            if skip.contains(&Step::PartOne) && skip.contains(&Step::PartTwo) {
+                return;
+            }
+        }
+    }
+
+    /// Records the outcome and timing of a step.
+    ///
+    /// If parsing the input fails, the parts can't be run either, so they get the same failure.
+    fn record(&mut self, step: Step, outcome: StepOutcome, timing: Timing) {
+        match (step, outcome) {
+            (Step::Parse, Ok(_)) => self.parse = timing,
+            (Step::Parse, Err(failure)) => {
+                self.parse = timing;
+                self.part_one.failure = Some(failure.clone());
+                self.part_two.failure = Some(failure.clone());
+                self.parse_failure = Some(failure);
+            }
+            (step, outcome) => {
+                let part_res = self.part_mut(step);
+                part_res.timing = timing;
+                match outcome {
+                    Ok(solution) => part_res.solution = solution,
+                    Err(failure) => part_res.failure = Some(failure),
+                }
+            }
+        }
+    }
+
+    fn part_mut(&mut self, step: Step) -> &mut PartResult {
+        match step {
+            Step::PartTwo => &mut self.part_two,
+            _ => &mut self.part_one,
+        }
+    }
+
+    /// Adds the expected answers for both parts so that the solutions can be verified.
+    pub fn check_answers(&mut self, answers: &Answers) {
+        self.part_one.expected = answers.get(self.year, self.day, self.input.as_deref(), 1);
+        self.part_two.expected = answers.get(self.year, self.day, self.input.as_deref(), 2);
+    }
+
+    /// Checks if any part's solution doesn't match its expected answer.
+    pub fn has_incorrect(&self) -> bool {
+        self.parts()
+            .iter()
+            .any(|(_, part_res)| part_res.is_correct() == Some(false))
+    }
+
+    /// Checks if the input couldn't be parsed, any part panicked or has a solution that doesn't match its expected answer.
+    pub fn has_failures(&self) -> bool {
+        self.has_incorrect() || self.has_panics()
+    }
+
+    /// Checks if the input couldn't be parsed, or parsing or any part panicked.
+    pub fn has_panics(&self) -> bool {
+        self.parts()
+            .iter()
+            .any(|(_, part_res)| matches!(part_res.status(), PartStatus::Error | PartStatus::Panic))
+    }
+
+    /// The worst status of the parts that were run.
+    pub fn status(&self) -> PartStatus {
+        self.parts()
+            .iter()
+            .map(|(_, part_res)| part_res.status())
+            .max()
+            .unwrap_or(PartStatus::Solved)
+    }
+
+    /// Gets the results of the parts that were run along with their part numbers.
+    pub fn parts(&self) -> Vec<(u8, &PartResult)> {
+        [(1, &self.part_one), (2, &self.part_two)]
+            .into_iter()
+            .filter(|(_, part_res)| !part_res.skipped)
+            .collect()
+    }
+
+    /// The title of the puzzle, followed by the name of the input if it isn't the main input.
+    pub fn title_with_input(&self) -> String {
+        match &self.input {
+            Some(input) => format!("{} ({input})", self.title),
+            None => self.title.to_string(),
+        }
+    }
+
+    /// The total time spent parsing the input and running the parts.
+    pub fn total_duration(&self) -> Duration {
+        self.parse.duration + self.part_one.timing.duration + self.part_two.timing.duration
+    }
+}
+
+/// The solution of a step that finished, or why it failed.
+type StepOutcome = Result<Answer, Failure>;
+
+/// Runs a step once, or a given number of times if `bench_runs` is given, and returns its outcome and timing.
+///
+/// Panics in the step are caught and returned as a [`Failure::Panic`], and parse errors as a [`Failure::Error`].
+fn time_step(
+    step_fn: &mut dyn FnMut() -> Result<Answer, SolveError>,
+    bench_runs: Option<u32>,
+) -> (StepOutcome, Timing) {
+    let mut failure = None;
+    let mut allocs = None;
+    let mut guarded_fn = || {
+        // Don't keep running a step that has already failed
+        if failure.is_some() {
+            return Answer::Unsolved;
+        }
+        let (outcome, step_allocs) = alloc::measure(|| catch_panic(step_fn));
+        allocs = step_allocs;
+        outcome.unwrap_or_else(|err| {
+            failure = Some(err);
+            Answer::Unsolved
+        })
+    };
+
+    let (solution, duration, stats) = if let Some(runs) = bench_runs {
+        let (solution, stats) = bench::bench_step(&mut guarded_fn, runs as usize);
+        let duration = stats.as_ref().map_or(Duration::ZERO, |stats| stats.median);
+        (solution, duration, stats)
+    } else {
+        let time = Instant::now();
+        let solution = guarded_fn();
+        (solution, time.elapsed(), None)
+    };
+    let timing = Timing {
+        duration,
+        stats,
+        allocs,
+    };
+
+    match failure {
+        Some(failure) => (Err(failure), timing),
+        None => (Ok(solution), timing),
+    }
+}
+
+thread_local! {
+    /// Whether the current thread is running a step, in which case panics are caught and returned as the step's failure.
+    static IN_STEP: Cell<bool> = const { Cell::new(false) };
+    /// The message and location of the last panic in a step on the current thread.
+    static LAST_PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Installs a panic hook that records the message and location of panics in steps instead of printing them.
+///
+/// Panics outside of steps are passed on to the default hook.
+pub fn install_panic_hook() {
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        if !IN_STEP.get() {
+            return default_hook(info);
+        }
+
+        let mut message = panic_message(info.payload());
+        if let Some(location) = info.location() {
+            message = format!("{message} at {location}");
+        }
+        LAST_PANIC.set(Some(message));
+    }));
+}
+
+/// Calls a step function, catching any panic as a [`Failure::Panic`]. A returned error becomes a [`Failure::Error`].
+fn catch_panic(step_fn: &mut dyn FnMut() -> Result<Answer, SolveError>) -> StepOutcome {
+    IN_STEP.set(true);
+    let res = panic::catch_unwind(AssertUnwindSafe(step_fn));
+    IN_STEP.set(false);
+
+    match res {
+        Ok(outcome) => outcome.map_err(|err| Failure::Error(Box::new(err))),
+        Err(payload) => {
+            // The panic hook might not be installed, e.g. in tests, in which case the location is unknown
+            let message = LAST_PANIC
+                .take()
+                .unwrap_or_else(|| panic_message(payload.as_ref()));
+            Err(Failure::Panic(message))
+        }
+    }
+}
+
+/// Gets the message of a panic from its payload.
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+/// A [`StepRunner`] that times each step and records the results in a [`DayResult`].
+struct TimingRunner<'a> {
+    res: &'a mut DayResult,
+    options: RunOptions,
+}
+
+impl StepRunner for TimingRunner<'_> {
+    fn run_step(&mut self, step: Step, step_fn: &mut dyn FnMut() -> Result<Answer, SolveError>) {
+        if !self.options.parts.includes(step) {
+            return;
+        }
+
+        let (outcome, timing) = time_step(step_fn, self.options.bench_runs);
+        self.res.record(step, outcome, timing);
+    }
+}
+
+/// An event sent by a [`ChannelRunner`] when it starts or finishes a step.
+enum StepEvent {
+    Started(Step),
+    Finished(Step, StepOutcome, Timing),
+}
+
+/// A [`StepRunner`] that times each step and sends the results over a channel, so that it can run on a worker thread.
+struct ChannelRunner {
+    sender: Sender<StepEvent>,
+    bench_runs: Option<u32>,
+    /// Steps that shouldn't be run, because they have already finished, timed out or weren't selected.
+    skip: Vec<Step>,
+}
+
+impl StepRunner for ChannelRunner {
+    fn run_step(&mut self, step: Step, step_fn: &mut dyn FnMut() -> Result<Answer, SolveError>) {
+        if self.skip.contains(&step) {
+            return;
+        }
+
+        let _ = self.sender.send(StepEvent::Started(step));
+        let (outcome, timing) = time_step(step_fn, self.bench_runs);
+        let _ = self.sender.send(StepEvent::Finished(step, outcome, timing));
+    }
+}
+
+/// The outcome of trying to run a day that has an input.
+pub enum DayRun {
+    /// The day has an input but no registered solution.
+    Unregistered(u8),
+    /// The results of solving each of the day's inputs.
+    Ran(Vec<DayResult>),
+}
+
+/// Why a run failed, which is returned as the exit code so that scripts can tell failures apart.
+///
+/// The statuses are ordered from best to worst, and a run exits with the worst status of its days.
+/// Exit code 2 is left out, since it's used for invalid arguments.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RunStatus {
+    Success = 0,
+    /// Some parts got slower than in the previous runs, with `--compare`.
+    Slower = 1,
+    /// A solved day's input couldn't be read.
+    MissingInput = 3,
+    /// A part's solution doesn't match its expected answer.
+    Incorrect = 4,
+    /// A part didn't finish within the time limit.
+    Timeout = 5,
+    /// A part panicked, or the input couldn't be parsed.
+    Panic = 6,
+    /// The answers, the history or the README couldn't be read or written, e.g. because the file isn't valid TOML.
+    BadFile = 7,
+    /// The day run with `--day` has no registered solution, so there are no answers.
+    Unregistered = 8,
+}
+
+impl RunStatus {
+    /// Gets the status of a day from the worst status of its parts. Unsolved parts aren't failures.
+    pub fn of(res: &DayResult) -> Self {
+        match res.status() {
+            PartStatus::Solved | PartStatus::Unsolved => Self::Success,
+            PartStatus::Incorrect => Self::Incorrect,
+            PartStatus::Timeout => Self::Timeout,
+            PartStatus::Error | PartStatus::Panic => Self::Panic,
+        }
+    }
+}
+
+impl From<RunStatus> for ExitCode {
+    fn from(status: RunStatus) -> Self {
+        Self::from(status as u8)
+    }
+}
+
+/// Where the inputs of each day are read from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    /// The day's `input.txt` and the named inputs in its `inputs` directory.
+    Inputs(InputDir),
+    /// The day's examples, which are always read from the [`InputDir::source`] directory.
+    Examples,
+    /// A given file, or stdin if the path is `-`, which is the only input of the day and has no name.
+    File(PathBuf),
+}
+
+/// Runs the days of a year on their inputs, checks their answers and reports the results.
+pub struct YearRunner<'a> {
+    pub year: u16,
+    pub inputs: InputSource,
+    pub options: RunOptions,
+    /// The expected answers to check the results against.
+    pub answers: &'a Answers,
+}
+
+impl YearRunner<'_> {
+    /// Runs a day's solution on each of its inputs without reporting the results.
+    pub fn solve_day(&self, day: u8) -> io::Result<DayRun> {
+        let inputs = match &self.inputs {
+            InputSource::Inputs(dir) => dir.read_inputs(day)?,
+            InputSource::Examples => InputDir::source().year(self.year).read_examples(day)?,
+            InputSource::File(path) => vec![Input {
+                name: None,
+                path: path.clone(),
+                text: read_input_file(path)?,
+            }],
+        };
+
+        let Some(solution) = days::get_solution(self.year, day) else {
+            return Ok(DayRun::Unregistered(day));
+        };
+
+        let results = inputs
+            .into_iter()
+            .map(|input| self.solve_input(solution, input))
+            .collect();
+        Ok(DayRun::Ran(results))
+    }
+
+    /// Runs a day's solution on a given input without reporting the results.
+    pub fn solve_input(&self, solution: &'static RegisteredSolution, input: Input) -> DayResult {
+        let mut res = DayResult::from_solution(&input.text, solution, self.options);
+        res.input = input.name;
+        res.input_path = Some(input.path);
+        res.check_answers(self.answers);
+        res
+    }
+
+    /// Runs a day that was asked for by itself and reports the results.
+    ///
+    /// Returns the status of the day along with its results.
+    pub fn run_day(&self, day: u8, reporter: &mut Reporter) -> (RunStatus, Vec<DayResult>) {
+        let run = match days::get_solution(self.year, day) {
+            // There's nothing to run without a solution, so a missing input doesn't matter
+            None => Ok(DayRun::Unregistered(day)),
+            Some(_) => self.solve_day(day),
+        };
+        match run {
+            Ok(run) => {
+                let status = report_day(&run, reporter);
+                match run {
+                    DayRun::Ran(results) => (status, results),
+                    // Running all days skips the unsolved ones, but a day that was asked for by itself should be solved
+                    DayRun::Unregistered(_) => (RunStatus::Unregistered, vec![]),
+                }
+            }
+            Err(err) => {
+                reporter.report_missing_input(day, &err.to_string());
+                (RunStatus::MissingInput, vec![])
+            }
+        }
+    }
+
+    /// Runs the given days on `jobs` worker threads and reports their results in order, followed by the totals.
+    /// Unsolved days without an input are skipped.
+    ///
+    /// Returns the worst status of the days along with their results.
+    pub fn run_days(
+        &self,
+        days: &[u8],
+        jobs: usize,
+        reporter: &mut Reporter,
+    ) -> (RunStatus, Vec<DayResult>) {
+        let mut status = RunStatus::Success;
+        let mut results = Vec::new();
+        let mut solving_time = Duration::ZERO;
+        let time = Instant::now();
+
+        run_in_order(
+            days,
+            jobs,
+            |day| self.solve_day(day),
+            |day, run| match run {
+                Ok(run) => {
+                    // Keep running the other days even if one of them is incorrect or panicked
+                    status = status.max(report_day(&run, reporter));
+                    if let DayRun::Ran(day_results) = run {
+                        solving_time += day_results.iter().map(DayResult::total_duration).sum();
+                        results.extend(day_results);
+                    }
+                }
+                // Unsolved days without an input are skipped, but solved ones should have one
+                Err(err) if days::get_solution(self.year, day).is_some() => {
+                    reporter.report_missing_input(day, &err.to_string());
+                    status = status.max(RunStatus::MissingInput);
+                }
+                Err(_) => {}
+            },
+        );
+
+        reporter.report_totals(time.elapsed(), solving_time);
+        (status, results)
+    }
+
+    /// Runs a day whenever its input or example file is modified, until the process is stopped.
+    ///
+    /// The example is run too if the day has an `example.txt`, but its answers aren't verified.
+    pub fn watch_day(&self, day: u8, format: OutputFormat) -> ! {
+        let example_path = InputDir::source()
+            .year(self.year)
+            .day_dir(day)
+            .join("example.txt");
+        let input_path = match &self.inputs {
+            InputSource::Inputs(dir) => dir.input_path(day),
+            InputSource::Examples => example_path.clone(),
+            InputSource::File(path) => path.clone(),
+        };
+        let modified_times = || {
+            [&input_path, &example_path]
+                .map(|path| fs::metadata(path).and_then(|meta| meta.modified()).ok())
+        };
+        let examples = YearRunner {
+            inputs: InputSource::Examples,
+            answers: &Answers::default(),
+            ..*self
+        };
+
+        let mut last_modified: Option<[Option<SystemTime>; 2]> = None;
+        loop {
+            let modified = modified_times();
+            if last_modified != Some(modified) {
+                last_modified = Some(modified);
+
+                let mut reporter = Reporter::new(format);
+                if format == OutputFormat::Text {
+                    // Clear the screen and move the cursor to the top
+                    print!("\x1B[2J\x1B[1;1H");
+                }
+                reporter.begin(self.year, false);
+
+                match self.solve_file(day, &input_path) {
+                    Ok(run) => {
+                        report_day(&run, &mut reporter);
+                    }
+                    Err(err) => reporter.report_missing_input(day, &err.to_string()),
+                }
+                if input_path != example_path {
+                    if let Ok(run) = examples.solve_file(day, &example_path) {
+                        reporter.report_heading("Example");
+                        report_day(&run, &mut reporter);
+                    }
+                }
+
+                reporter.finish();
+                if format == OutputFormat::Text {
+                    println!("{}", "Watching for changes...".dimmed());
+                }
+            }
+            thread::sleep(Duration::from_millis(500));
+        }
+    }
+
+    /// Runs a day's solution on the input in a given file, which has no name.
+    fn solve_file(&self, day: u8, path: &Path) -> io::Result<DayRun> {
+        let input = Input {
+            name: None,
+            path: path.to_path_buf(),
+            text: read_input_file(path)?,
+        };
+        Ok(match days::get_solution(self.year, day) {
+            Some(solution) => DayRun::Ran(vec![self.solve_input(solution, input)]),
+            None => DayRun::Unregistered(day),
+        })
+    }
+}
+
+/// Reports the results of a day for each of its inputs.
+/// If the day has several inputs, the ones it failed on are listed at the end.
+///
+/// Returns the worst status of the day's inputs.
+pub fn report_day(run: &DayRun, reporter: &mut Reporter) -> RunStatus {
+    match run {
+        DayRun::Unregistered(day) => {
+            reporter.report_unregistered(*day);
+            RunStatus::Success
+        }
+        DayRun::Ran(results) => {
+            for res in results {
+                reporter.report_day(res);
+            }
+
+            let failing = results
+                .iter()
+                .filter(|res| res.has_failures())
+                .map(|res| res.input.as_deref().unwrap_or("input.txt"))
+                .collect::<Vec<_>>();
+            if results.len() > 1 && !failing.is_empty() {
+                reporter.report_failing_inputs(results[0].day, &failing);
+            }
+            results
+                .iter()
+                .map(RunStatus::of)
+                .max()
+                .unwrap_or(RunStatus::Success)
+        }
+    }
+}
+
+/// Solves the given days on a pool of `jobs` worker threads.
+///
+/// The results are passed to `on_result` in the same order as the days, as soon as all of the preceding days are done.
+pub fn run_in_order<T, S, R>(days: &[u8], jobs: usize, solve: S, mut on_result: R)
+where
+    T: Send,
+    S: Fn(u8) -> T + Sync,
+    R: FnMut(u8, T),
+{
+    let next_idx = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, days.len().max(1)) {
+            let sender = sender.clone();
+            let (next_idx, solve) = (&next_idx, &solve);
+            scope.spawn(move || loop {
+                let idx = next_idx.fetch_add(1, Ordering::Relaxed);
+                let Some(&day) = days.get(idx) else {
+                    break;
+                };
+                if sender.send((idx, solve(day))).is_err() {
+                    break;
+                }
+            });
+        }
+        // Drop the original sender so that the receiver stops once all workers are done
+        drop(sender);
+
+        // Buffer results that finish early until the days before them are done
+        let mut pending: Vec<Option<T>> = days.iter().map(|_| None).collect();
+        let mut next_to_report = 0;
+        for (idx, res) in receiver {
+            pending[idx] = Some(res);
+            while let Some(res) = pending.get_mut(next_to_report).and_then(Option::take) {
+                on_result(days[next_to_report], res);
+                next_to_report += 1;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::Solution;
+
+    /// A puzzle whose part one doesn't finish within the timeouts used in the tests.
+    struct SlowPartPuzzle;
+
+    impl Solution for SlowPartPuzzle {
+        const DAY: u8 = 1;
+        const TITLE: &'static str = "Slow Part";
+
+        type Input<'a> = &'a str;
+        type AnswerOne = u32;
+        type AnswerTwo = usize;
+
+        fn parse(input: &str) -> Result<Self::Input<'_>, SolveError> {
+            Ok(input)
+        }
+
+        fn part_one(_input: &Self::Input<'_>) -> Option<Self::AnswerOne> {
+            thread::sleep(Duration::from_millis(500));
+            Some(1)
+        }
+
+        fn part_two(input: &Self::Input<'_>) -> Option<Self::AnswerTwo> {
+            Some(input.len())
+        }
+    }
+
+    /// How many times [`SlowReparsePuzzle`] has parsed its input.
+    static REPARSES: AtomicUsize = AtomicUsize::new(0);
+
+    /// A puzzle whose part one is slow, and whose parsing is only fast the first time.
+    struct SlowReparsePuzzle;
+
+    impl Solution for SlowReparsePuzzle {
+        const DAY: u8 = 2;
+        const TITLE: &'static str = "Slow Reparse";
+
+        type Input<'a> = &'a str;
+        type AnswerOne = u32;
+        type AnswerTwo = usize;
+
+        fn parse(input: &str) -> Result<Self::Input<'_>, SolveError> {
+            if REPARSES.fetch_add(1, Ordering::Relaxed) > 0 {
+                thread::sleep(Duration::from_millis(500));
+            }
+            Ok(input)
+        }
+
+        fn part_one(_input: &Self::Input<'_>) -> Option<Self::AnswerOne> {
+            thread::sleep(Duration::from_millis(500));
+            Some(1)
+        }
+
+        fn part_two(input: &Self::Input<'_>) -> Option<Self::AnswerTwo> {
+            Some(input.len())
+        }
+    }
+
+    static SLOW_PART: RegisteredSolution = RegisteredSolution::of::<SlowPartPuzzle>(2022);
+    static SLOW_REPARSE: RegisteredSolution = RegisteredSolution::of::<SlowReparsePuzzle>(2022);
+
+    fn with_timeout(millis: u64) -> RunOptions {
+        RunOptions {
+            timeout: Some(Duration::from_millis(millis)),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_timeout() {
+        let res = DayResult::from_solution("input", &SLOW_PART, with_timeout(50));
+
+        assert_eq!(res.parse_failure, None);
+        assert_eq!(res.part_one.status(), PartStatus::Timeout);
+        // Part two is run on a new thread after part one times out
+        assert_eq!(res.part_two.status(), PartStatus::Solved);
+        assert_eq!(res.part_two.solution, Answer::Integer(5));
+    }
+
+    #[test]
+    fn test_reparse_timeout() {
+        let res = DayResult::from_solution("input", &SLOW_REPARSE, with_timeout(50));
+
+        // The first parse finished in time, only the parse before rerunning part two timed out
+        assert_eq!(res.parse_failure, None);
+        assert!(res.parse.duration < Duration::from_millis(50));
+        assert_eq!(res.part_one.status(), PartStatus::Timeout);
+        assert_eq!(res.part_two.status(), PartStatus::Timeout);
+        assert_eq!(res.part_two.solution, Answer::Unsolved);
+    }
+
+    #[test]
+    fn test_run_in_order() {
+        let days = (1..=25).collect::<Vec<u8>>();
+        let mut reported = vec![];
+
+        // Later days finish first, but they should still be reported in order
+        run_in_order(
+            &days,
+            8,
+            |day| {
+                thread::sleep(Duration::from_micros(100 * (25 - day as u64)));
+                day * 2
+            },
+            |day, res| reported.push((day, res)),
+        );
+
+        assert_eq!(
+            reported,
+            days.iter().map(|&day| (day, day * 2)).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_run_status() {
+        let worst = [
+            RunStatus::Timeout,
+            RunStatus::MissingInput,
+            RunStatus::Incorrect,
+        ]
+        .into_iter()
+        .max();
+        assert_eq!(worst, Some(RunStatus::Timeout));
+        assert_eq!(ExitCode::from(RunStatus::Panic), ExitCode::from(6));
+    }
+
+    #[test]
+    fn test_part_status() {
+        let part = |solution: Answer, expected: Option<Answer>| PartResult {
+            solution,
+            expected,
+            ..Default::default()
+        };
+
+        assert_eq!(part(Answer::Unsolved, None).status(), PartStatus::Unsolved);
+        assert_eq!(part(Answer::from(2), None).status(), PartStatus::Solved);
+        assert_eq!(
+            part(Answer::from(2), Some(Answer::from(2))).status(),
+            PartStatus::Solved
+        );
+        assert_eq!(
+            part(Answer::from(3), Some(Answer::from(2))).status(),
+            PartStatus::Incorrect
+        );
+        // A part that regressed to returning no answer
+        assert_eq!(
+            part(Answer::Unsolved, Some(Answer::from(2))).status(),
+            PartStatus::Incorrect
+        );
+    }
+
+    #[test]
+    fn test_step_panic() {
+        let (outcome, _) = time_step(&mut || panic!("invalid input"), None);
+        assert_eq!(outcome, Err(Failure::Panic("invalid input".to_string())));
+
+        let (outcome, _) = time_step(&mut || Ok(Answer::from("CMZ")), Some(3));
+        assert_eq!(outcome, Ok(Answer::Text("CMZ".to_string())));
+
+        let err = SolveError::new("expected a number", "x");
+        let (outcome, _) = time_step(&mut || Err(err.clone()), None);
+        assert_eq!(outcome, Err(Failure::Error(Box::new(err))));
+    }
+}
@@ -0,0 +1,161 @@
+use crate::{answer::Answer, error::SolveError};
+
+/// A solution to a single day's puzzle.
+///
+/// Every `days::yearNNNN::dayNN` module implements this for its `Puzzle` type, which is then picked up by the registry in [`crate::days`].
+///
+/// The input is parsed once with [`Solution::parse`], and both parts then borrow the parsed input.
+/// Parsing is where malformed input is caught, so that the parts can rely on the parsed input being valid.
+pub trait Solution {
+    /// The day of the puzzle, from 1 to 25.
+    const DAY: u8;
+    /// The title of the puzzle, e.g. "Calorie Counting".
+    const TITLE: &'static str;
+
+    /// The parsed input that both parts are solved from.
+    type Input<'a>;
+    /// The types of the answers to each part, e.g. a number or a string. They're converted to an [`Answer`] for the runner.
+    type AnswerOne: Into<Answer>;
+    type AnswerTwo: Into<Answer>;
+
+    /// Parses the input, or fails with a [`SolveError`] pointing to the text that couldn't be parsed.
+    fn parse(input: &str) -> Result<Self::Input<'_>, SolveError>;
+    fn part_one(input: &Self::Input<'_>) -> Option<Self::AnswerOne>;
+    fn part_two(input: &Self::Input<'_>) -> Option<Self::AnswerTwo>;
+
+    /// Parses the input and solves part one. Used by the tests of each day.
+    ///
+    /// Trailing newlines are trimmed from the input, like the runner does for example files.
+    #[cfg(test)]
+    fn solve_part_one(input: &str) -> Option<Self::AnswerOne> {
+        Self::part_one(&Self::parse_for_test(input))
+    }
+
+    /// Parses the input and solves part two. Used by the tests of each day.
+    ///
+    /// Trailing newlines are trimmed from the input, like the runner does for example files.
+    #[cfg(test)]
+    fn solve_part_two(input: &str) -> Option<Self::AnswerTwo> {
+        Self::part_two(&Self::parse_for_test(input))
+    }
+
+    /// Parses an input in a test, panicking with the located error if it's malformed.
+    #[cfg(test)]
+    fn parse_for_test(input: &str) -> Self::Input<'_> {
+        let input = input.trim_end_matches(['\n', '\r']);
+        Self::parse(input).unwrap_or_else(|err| panic!("{}", err.locate(input)))
+    }
+}
+
+/// A step of running a solution.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Step {
+    Parse,
+    PartOne,
+    PartTwo,
+}
+
+/// Runs the steps of a solution, letting the runner decide how each step is timed or measured.
+pub trait StepRunner {
+    /// Runs a given step by calling `step_fn` as many times as needed.
+    ///
+    /// `step_fn` returns the solution for parts and [`Answer::Unsolved`] for the parse step,
+    /// or the error if the input couldn't be parsed.
+    fn run_step(&mut self, step: Step, step_fn: &mut dyn FnMut() -> Result<Answer, SolveError>);
+}
+
+/// A type-erased [`Solution`] that can be stored in the registry and run by the runner.
+pub struct RegisteredSolution {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    /// Parses the input and runs both parts through a [`StepRunner`].
+    pub run: fn(&str, &mut dyn StepRunner),
+}
+
+impl RegisteredSolution {
+    /// Registers a solution to a puzzle from a given year.
+    pub const fn of<S: Solution>(year: u16) -> Self {
+        Self {
+            year,
+            day: S::DAY,
+            title: S::TITLE,
+            run: run_erased::<S>,
+        }
+    }
+}
+
+fn run_erased<S: Solution>(input: &str, runner: &mut dyn StepRunner) {
+    let mut parsed = None;
+    runner.run_step(Step::Parse, &mut || {
+        parsed = Some(S::parse(input).map_err(|err| err.locate(input))?);
+        Ok(Answer::Unsolved)
+    });
+    let Some(parsed) = parsed else {
+        return;
+    };
+
+    runner.run_step(Step::PartOne, &mut || {
+        Ok(S::part_one(&parsed).map_or(Answer::Unsolved, Into::into))
+    });
+    runner.run_step(Step::PartTwo, &mut || {
+        Ok(S::part_two(&parsed).map_or(Answer::Unsolved, Into::into))
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A solution whose parts have different answer types.
+    struct MixedPuzzle;
+
+    impl Solution for MixedPuzzle {
+        const DAY: u8 = 10;
+        const TITLE: &'static str = "Mixed Answers";
+
+        type Input<'a> = Vec<&'a str>;
+        type AnswerOne = usize;
+        type AnswerTwo = String;
+
+        fn parse(input: &str) -> Result<Self::Input<'_>, SolveError> {
+            Ok(input.lines().collect())
+        }
+
+        fn part_one(input: &Self::Input<'_>) -> Option<Self::AnswerOne> {
+            Some(input.len())
+        }
+
+        fn part_two(input: &Self::Input<'_>) -> Option<Self::AnswerTwo> {
+            Some(input.concat())
+        }
+    }
+
+    /// A [`StepRunner`] that runs each step once and records the answers.
+    struct RecordingRunner(Vec<(Step, Result<Answer, SolveError>)>);
+
+    impl StepRunner for RecordingRunner {
+        fn run_step(
+            &mut self,
+            step: Step,
+            step_fn: &mut dyn FnMut() -> Result<Answer, SolveError>,
+        ) {
+            self.0.push((step, step_fn()));
+        }
+    }
+
+    #[test]
+    fn test_mixed_answer_types() {
+        let mut runner = RecordingRunner(vec![]);
+        (RegisteredSolution::of::<MixedPuzzle>(2022).run)("AB\nCD", &mut runner);
+
+        assert_eq!(
+            runner.0,
+            [
+                (Step::Parse, Ok(Answer::Unsolved)),
+                (Step::PartOne, Ok(Answer::Integer(2))),
+                (Step::PartTwo, Ok(Answer::Text("ABCD".to_string()))),
+            ]
+        );
+    }
+}
@@ -1,13 +1,26 @@
-use std::{collections::BTreeSet, fmt::Debug, str::FromStr};
+use std::{collections::BTreeSet, str::FromStr};
 
-/// Parses a string iterator's strings into a given type
-pub fn parse_strings<'a, I, T>(items: I) -> Vec<T>
+use crate::error::SolveError;
+
+/// Parses a string iterator's strings into numbers
+pub fn parse_strings<'a, I, T>(items: I) -> Result<Vec<T>, SolveError>
 where
     I: Iterator<Item = &'a str>,
     T: FromStr,
-    <T as std::str::FromStr>::Err: Debug,
 {
-    items.map(|item| item.parse::<T>().unwrap()).collect()
+    items.map(parse_number).collect()
+}
+
+/// Parses a number from a slice of the input.
+pub fn parse_number<T: FromStr>(text: &str) -> Result<T, SolveError> {
+    text.parse::<T>()
+        .map_err(|_| SolveError::new("expected a number", text))
+}
+
+/// Splits a slice of the input at the first occurrence of a delimiter.
+pub fn split_once<'a>(text: &'a str, delimiter: &str) -> Result<(&'a str, &'a str), SolveError> {
+    text.split_once(delimiter)
+        .ok_or_else(|| SolveError::new(format!("expected `{delimiter}`"), text))
 }
 
 pub fn intersection<'a, I, T>(mut sets: I) -> BTreeSet<T>
@@ -16,6 +29,6 @@ where
     T: Clone + Ord + 'a,
 {
     sets.next()
-        .map(|set| sets.fold(set.to_owned(), |set1, set2| (&set1 & set2)))
+        .map(|set| sets.fold(set.to_owned(), |set1, set2| &set1 & set2))
         .unwrap()
 }